@@ -0,0 +1,262 @@
+//! Alert rules and notifications.
+//!
+//! An `AlertRule` watches the ingested entries for a level threshold, a
+//! custom type or a message pattern, optionally only when more than a
+//! number of matching entries arrive within a time window ("more than 5
+//! errors in 10s"). A rule that fires raises an `Alert`, which the logger
+//! shows as a toast (`ReactiveEventLogger::show_alert_toasts`) and keeps
+//! in the notification center, from where the user can jump to the entry.
+use std::collections::VecDeque;
+use std::time::{Duration, Instant};
+
+use regex::Regex;
+
+use crate::group::severity;
+use crate::logger::LogType;
+use crate::payload::LoggerPayload;
+use crate::progress::format_duration;
+
+/// Maximum number of alerts kept in the notification center
+const MAX_ALERTS: usize = 100;
+
+/// AlertCondition
+///
+/// Selects the log entries an alert rule counts.
+#[derive(Clone, Debug)]
+pub enum AlertCondition {
+    /// Entries at or above a level, e.g. `LogType::Warning` for warnings and errors
+    MinLevel(LogType),
+    /// Entries of a custom type
+    CustomType(String),
+    /// Entries whose message matches a regular expression
+    Match(Regex),
+}
+
+impl AlertCondition {
+    /// Check if a log entry meets the condition
+    pub fn matches(&self, log: &LoggerPayload) -> bool {
+        match self {
+            AlertCondition::MinLevel(level) => {
+                // Custom types rank like INFO
+                let min = severity(level.level_text().unwrap_or("CUSTOM"));
+                !log.level().is_empty() && severity(log.level()) >= min
+            }
+            AlertCondition::CustomType(identifier) => log.level().strip_prefix("CUSTOM:") == Some(identifier.as_str()),
+            AlertCondition::Match(regex) => regex.is_match(&log.log_message.content.value),
+        }
+    }
+}
+
+/// AlertRule
+///
+/// Raises an alert for matching entries, e.g.
+/// `AlertRule::level("Error burst", LogType::Error).more_than(5, Duration::from_secs(10))`.
+#[derive(Clone, Debug)]
+pub struct AlertRule {
+    /// Name shown as the title of the alert
+    pub name: String,
+    /// Entries the rule counts
+    pub condition: AlertCondition,
+    /// Only fire when more than this many entries match within the window
+    pub rate: Option<(usize, Duration)>,
+    /// Arrival times of the matching entries within the window
+    recent: VecDeque<Instant>,
+}
+
+impl AlertRule {
+    /// Rule for entries at or above a level
+    pub fn level(name: &str, level: LogType) -> Self {
+        Self::new(name, AlertCondition::MinLevel(level))
+    }
+
+    /// Rule for entries of a custom type
+    pub fn custom_type(name: &str, identifier: &str) -> Self {
+        Self::new(name, AlertCondition::CustomType(identifier.to_string()))
+    }
+
+    /// Rule for messages matching a regular expression
+    pub fn matching(name: &str, pattern: &str) -> Result<Self, regex::Error> {
+        Ok(Self::new(name, AlertCondition::Match(Regex::new(pattern)?)))
+    }
+
+    fn new(name: &str, condition: AlertCondition) -> Self {
+        Self {
+            name: name.to_string(),
+            condition,
+            rate: None,
+            recent: VecDeque::new(),
+        }
+    }
+
+    /// Only fire when more than `count` entries match within `window`
+    pub fn more_than(mut self, count: usize, window: Duration) -> Self {
+        self.rate = Some((count, window));
+        self
+    }
+
+    /// Count an ingested entry, returning the alert message if the rule fires
+    fn check(&mut self, log: &LoggerPayload, now: Instant) -> Option<String> {
+        if !self.condition.matches(log) {
+            return None;
+        }
+
+        let message = &log.log_message.content.value;
+        let Some((count, window)) = self.rate else {
+            return Some(message.clone());
+        };
+
+        // Forget matches that left the window
+        self.recent.push_back(now);
+        while self.recent.front().is_some_and(|time| now.saturating_duration_since(*time) > window) {
+            self.recent.pop_front();
+        }
+        if self.recent.len() <= count {
+            return None;
+        }
+
+        // Start counting again after firing
+        let matched = self.recent.len();
+        self.recent.clear();
+        Some(format!("{} entries in {}, last: {}", matched, format_duration(window), message))
+    }
+}
+
+/// Alert
+///
+/// A fired alert rule, pointing at the entry that triggered it.
+#[derive(Clone, Debug)]
+pub struct Alert {
+    /// Unique id of the alert
+    pub id: u64,
+    /// Name of the rule that fired
+    pub rule: String,
+    /// Description of what happened
+    pub message: String,
+    /// Level text of the triggering entry
+    pub level: String,
+    /// Sequence number of the triggering entry
+    pub sequence: u64,
+    /// When the alert was raised
+    pub raised_at: chrono::DateTime<chrono::Local>,
+    /// Whether the user has looked at the notification center since
+    pub seen: bool,
+    /// Whether the alert is still shown as a toast
+    pub toast: bool,
+}
+
+/// Alerts
+///
+/// The alert rules of a logger state and the alerts they raised.
+#[derive(Clone, Debug, Default)]
+pub struct Alerts {
+    /// Rules checked for every ingested entry
+    pub rules: Vec<AlertRule>,
+    /// Raised alerts, oldest first
+    pub raised: Vec<Alert>,
+    next_id: u64,
+}
+
+impl Alerts {
+    /// Add a rule
+    pub fn add_rule(&mut self, rule: AlertRule) {
+        self.rules.push(rule);
+    }
+
+    /// Check the rules against an ingested entry
+    pub(crate) fn check(&mut self, log: &LoggerPayload, now: Instant) {
+        for rule in &mut self.rules {
+            if let Some(message) = rule.check(log, now) {
+                self.raised.push(Alert {
+                    id: self.next_id,
+                    rule: rule.name.clone(),
+                    message,
+                    level: log.level().to_string(),
+                    sequence: log.sequence,
+                    raised_at: chrono::Local::now(),
+                    seen: false,
+                    toast: true,
+                });
+                self.next_id += 1;
+            }
+        }
+
+        let excess = self.raised.len().saturating_sub(MAX_ALERTS);
+        self.raised.drain(..excess);
+    }
+
+    /// Number of alerts not yet seen in the notification center
+    pub fn unseen_count(&self) -> usize {
+        self.raised.iter().filter(|alert| !alert.seen).count()
+    }
+
+    /// Mark all alerts as seen
+    pub fn mark_seen(&mut self) {
+        for alert in &mut self.raised {
+            alert.seen = true;
+        }
+    }
+
+    /// Close the toast of an alert, keeping it in the notification center
+    pub fn dismiss(&mut self, id: u64) {
+        if let Some(alert) = self.raised.iter_mut().find(|alert| alert.id == id) {
+            alert.toast = false;
+        }
+    }
+
+    /// Remove all alerts
+    pub fn clear(&mut self) {
+        self.raised.clear();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn entry(level: &str, message: &str) -> LoggerPayload {
+        let mut log = LoggerPayload::new();
+        match level {
+            "ERROR" => log.error(),
+            "WARNING" => log.warning(),
+            "DEBUG" => log.debug(),
+            custom => log.custom_type(custom),
+        };
+        log.message(message.to_string()).update();
+        log
+    }
+
+    #[test]
+    fn test_alert_conditions() {
+        let warnings = AlertCondition::MinLevel(LogType::Warning);
+        assert!(warnings.matches(&entry("ERROR", "failed")));
+        assert!(warnings.matches(&entry("WARNING", "slow")));
+        assert!(!warnings.matches(&entry("DEBUG", "tick")));
+        assert!(!warnings.matches(&entry("network", "connected")));
+
+        let network = AlertCondition::CustomType("network".to_string());
+        assert!(network.matches(&entry("network", "connected")));
+        assert!(!network.matches(&entry("database", "connected")));
+    }
+
+    #[test]
+    fn test_alert_rate() {
+        let start = Instant::now();
+        let mut alerts = Alerts::default();
+        alerts.add_rule(AlertRule::level("Error burst", LogType::Error).more_than(2, Duration::from_secs(10)));
+
+        // Two errors, then a third outside the window of the first
+        alerts.check(&entry("ERROR", "a"), start);
+        alerts.check(&entry("ERROR", "b"), start + Duration::from_secs(5));
+        alerts.check(&entry("ERROR", "c"), start + Duration::from_secs(12));
+        assert!(alerts.raised.is_empty());
+
+        // A fourth within the window fires
+        alerts.check(&entry("ERROR", "d"), start + Duration::from_secs(13));
+        assert_eq!(alerts.raised.len(), 1);
+        assert_eq!(alerts.raised[0].message, "3 entries in 10s, last: d");
+        assert_eq!(alerts.unseen_count(), 1);
+
+        alerts.mark_seen();
+        assert_eq!(alerts.unseen_count(), 0);
+    }
+}
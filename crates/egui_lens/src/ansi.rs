@@ -0,0 +1,342 @@
+//! ANSI escape sequence handling for log messages.
+//!
+//! Output forwarded from command line tools often carries ANSI SGR
+//! ("Select Graphic Rendition") color codes. This module converts those
+//! sequences into egui `LayoutJob` sections so they render as colored text,
+//! or strips them entirely for plain text views and exports.
+//!
+//! Supported SGR attributes:
+//! - 16 standard and bright colors (30-37, 40-47, 90-97, 100-107)
+//! - 256 color palette (38;5;n / 48;5;n)
+//! - Truecolor (38;2;r;g;b / 48;2;r;g;b)
+//! - Bold, dim, italic, underline, strikethrough and their resets
+//!
+//! Any other escape sequence (cursor movement, erase line, OSC titles, ...)
+//! is silently dropped.
+use eframe::egui;
+use egui::text::{LayoutJob, TextFormat};
+
+const ESC: char = '\u{1b}';
+
+/// Standard 16 color palette (VGA-like), normal colors followed by bright colors
+const ANSI_PALETTE: [egui::Color32; 16] = [
+    egui::Color32::from_rgb(0, 0, 0),
+    egui::Color32::from_rgb(205, 49, 49),
+    egui::Color32::from_rgb(13, 188, 121),
+    egui::Color32::from_rgb(229, 229, 16),
+    egui::Color32::from_rgb(36, 114, 200),
+    egui::Color32::from_rgb(188, 63, 188),
+    egui::Color32::from_rgb(17, 168, 205),
+    egui::Color32::from_rgb(229, 229, 229),
+    egui::Color32::from_rgb(102, 102, 102),
+    egui::Color32::from_rgb(241, 76, 76),
+    egui::Color32::from_rgb(35, 209, 139),
+    egui::Color32::from_rgb(245, 245, 67),
+    egui::Color32::from_rgb(59, 142, 234),
+    egui::Color32::from_rgb(214, 112, 214),
+    egui::Color32::from_rgb(41, 184, 219),
+    egui::Color32::from_rgb(255, 255, 255),
+];
+
+/// AnsiStyle
+///
+/// The text attributes accumulated while walking through SGR sequences.
+/// `None` colors mean "use the default color of the log entry".
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+struct AnsiStyle {
+    foreground: Option<egui::Color32>,
+    background: Option<egui::Color32>,
+    bold: bool,
+    dim: bool,
+    italic: bool,
+    underline: bool,
+    strikethrough: bool,
+}
+
+impl AnsiStyle {
+    /// Build an egui TextFormat from the current attributes
+    fn to_text_format(self, font_id: &egui::FontId, default_color: egui::Color32) -> TextFormat {
+        let mut color = self.foreground.unwrap_or(default_color);
+
+        // egui has no bold monospace face, so emulate bold the way many
+        // terminals do: by brightening the color
+        if self.bold {
+            color = color.lerp_to_gamma(egui::Color32::WHITE, 0.35);
+        }
+        if self.dim {
+            color = color.lerp_to_gamma(egui::Color32::BLACK, 0.4);
+        }
+
+        TextFormat {
+            font_id: font_id.clone(),
+            color,
+            background: self.background.unwrap_or(egui::Color32::TRANSPARENT),
+            italics: self.italic,
+            underline: if self.underline {
+                egui::Stroke::new(1.0, color)
+            } else {
+                egui::Stroke::NONE
+            },
+            strikethrough: if self.strikethrough {
+                egui::Stroke::new(1.0, color)
+            } else {
+                egui::Stroke::NONE
+            },
+            ..Default::default()
+        }
+    }
+
+    /// Apply the parameters of a single SGR sequence
+    fn apply_sgr(&mut self, params: &[u16]) {
+        // An empty parameter list ("ESC[m") is equivalent to a reset
+        if params.is_empty() {
+            *self = Self::default();
+            return;
+        }
+
+        let mut i = 0;
+        while i < params.len() {
+            match params[i] {
+                0 => *self = Self::default(),
+                1 => self.bold = true,
+                2 => self.dim = true,
+                3 => self.italic = true,
+                4 => self.underline = true,
+                9 => self.strikethrough = true,
+                22 => {
+                    self.bold = false;
+                    self.dim = false;
+                }
+                23 => self.italic = false,
+                24 => self.underline = false,
+                29 => self.strikethrough = false,
+                code @ 30..=37 => self.foreground = Some(ANSI_PALETTE[(code - 30) as usize]),
+                38 => {
+                    let (color, consumed) = parse_extended_color(&params[i + 1..]);
+                    if color.is_some() {
+                        self.foreground = color;
+                    }
+                    i += consumed;
+                }
+                39 => self.foreground = None,
+                code @ 40..=47 => self.background = Some(ANSI_PALETTE[(code - 40) as usize]),
+                48 => {
+                    let (color, consumed) = parse_extended_color(&params[i + 1..]);
+                    if color.is_some() {
+                        self.background = color;
+                    }
+                    i += consumed;
+                }
+                49 => self.background = None,
+                code @ 90..=97 => self.foreground = Some(ANSI_PALETTE[(code - 90 + 8) as usize]),
+                code @ 100..=107 => self.background = Some(ANSI_PALETTE[(code - 100 + 8) as usize]),
+                _ => {} // Unsupported attributes (blink, reverse, ...) are ignored
+            }
+            i += 1;
+        }
+    }
+}
+
+/// Parse the tail of a 38/48 extended color sequence.
+///
+/// Returns the color (if valid) and the number of parameters consumed
+/// after the 38/48 code itself.
+fn parse_extended_color(params: &[u16]) -> (Option<egui::Color32>, usize) {
+    match params.first() {
+        // 256 color palette: 5;n
+        Some(5) => match params.get(1) {
+            Some(&n) => (Some(color_from_256(n.min(255) as u8)), 2),
+            None => (None, 1),
+        },
+        // Truecolor: 2;r;g;b
+        Some(2) => {
+            if params.len() >= 4 {
+                let channel = |v: u16| v.min(255) as u8;
+                (
+                    Some(egui::Color32::from_rgb(channel(params[1]), channel(params[2]), channel(params[3]))),
+                    4,
+                )
+            } else {
+                (None, params.len())
+            }
+        }
+        _ => (None, 0),
+    }
+}
+
+/// Convert an xterm 256 color palette index into a Color32
+fn color_from_256(index: u8) -> egui::Color32 {
+    match index {
+        0..=15 => ANSI_PALETTE[index as usize],
+        16..=231 => {
+            // 6x6x6 color cube
+            let index = index - 16;
+            let level = |v: u8| if v == 0 { 0 } else { 55 + v * 40 };
+            egui::Color32::from_rgb(level(index / 36), level((index / 6) % 6), level(index % 6))
+        }
+        232..=255 => {
+            // Grayscale ramp
+            let gray = 8 + (index - 232) * 10;
+            egui::Color32::from_rgb(gray, gray, gray)
+        }
+    }
+}
+
+/// A piece of the input text: either plain text or an escape sequence
+enum Token<'a> {
+    Text(&'a str),
+    Sgr(Vec<u16>),
+    Other,
+}
+
+/// Split the input into text runs and escape sequences
+fn tokenize(text: &str) -> Vec<Token<'_>> {
+    let mut tokens = Vec::new();
+    let mut rest = text;
+
+    while let Some(start) = rest.find(ESC) {
+        if start > 0 {
+            tokens.push(Token::Text(&rest[..start]));
+        }
+
+        let sequence = &rest[start + ESC.len_utf8()..];
+        let (token, consumed) = match sequence.chars().next() {
+            // CSI: ESC [ params final-byte
+            Some('[') => {
+                let body = &sequence[1..];
+                match body.find(|c: char| ('\u{40}'..='\u{7e}').contains(&c)) {
+                    Some(end) => {
+                        let final_byte = body[end..].chars().next().unwrap_or('m');
+                        let token = if final_byte == 'm' {
+                            Token::Sgr(
+                                body[..end]
+                                    .split([';', ':'])
+                                    .filter(|p| !p.is_empty())
+                                    .map(|p| p.parse::<u16>().unwrap_or(0))
+                                    .collect(),
+                            )
+                        } else {
+                            Token::Other
+                        };
+                        (token, 1 + end + final_byte.len_utf8())
+                    }
+                    // Unterminated sequence: drop the remainder
+                    None => (Token::Other, sequence.len()),
+                }
+            }
+            // OSC: ESC ] ... terminated by BEL or ESC \
+            Some(']') => {
+                let bel = sequence.find('\u{7}').map(|i| i + 1);
+                let st = sequence.find("\u{1b}\\").map(|i| i + 2);
+                let end = match (bel, st) {
+                    (Some(a), Some(b)) => a.min(b),
+                    (Some(a), None) | (None, Some(a)) => a,
+                    (None, None) => sequence.len(),
+                };
+                (Token::Other, end)
+            }
+            // Two byte escape (ESC c, ESC 7, ...)
+            Some(c) => (Token::Other, c.len_utf8()),
+            None => (Token::Other, 0),
+        };
+
+        tokens.push(token);
+        rest = &sequence[consumed..];
+    }
+
+    if !rest.is_empty() {
+        tokens.push(Token::Text(rest));
+    }
+
+    tokens
+}
+
+/// Returns true if the text contains at least one escape character
+pub fn contains_ansi(text: &str) -> bool {
+    text.contains(ESC)
+}
+
+/// Remove all ANSI escape sequences from the text
+pub fn strip_ansi(text: &str) -> String {
+    if !contains_ansi(text) {
+        return text.to_string();
+    }
+
+    tokenize(text)
+        .into_iter()
+        .filter_map(|token| match token {
+            Token::Text(text) => Some(text),
+            _ => None,
+        })
+        .collect()
+}
+
+/// Convert text containing ANSI SGR sequences into a LayoutJob.
+///
+/// Text without explicit foreground color uses `default_color`, so messages
+/// keep their log level coloring outside of the escaped regions.
+pub fn ansi_to_layout_job(text: &str, font_id: egui::FontId, default_color: egui::Color32) -> LayoutJob {
+    let mut job = LayoutJob::default();
+    let mut style = AnsiStyle::default();
+
+    for token in tokenize(text) {
+        match token {
+            Token::Text(text) => job.append(text, 0.0, style.to_text_format(&font_id, default_color)),
+            Token::Sgr(params) => style.apply_sgr(&params),
+            Token::Other => {}
+        }
+    }
+
+    job
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn sections(job: &LayoutJob) -> Vec<(&str, egui::Color32)> {
+        job.sections
+            .iter()
+            .map(|s| (&job.text[s.byte_range.clone()], s.format.color))
+            .collect()
+    }
+
+    #[test]
+    fn test_strip_ansi() {
+        assert_eq!(strip_ansi("\u{1b}[31merror\u{1b}[0m: failed"), "error: failed");
+        assert_eq!(strip_ansi("\u{1b}[2K\u{1b}[1;38;5;208mwarn\u{1b}[m"), "warn");
+        assert_eq!(strip_ansi("\u{1b}]0;title\u{7}plain"), "plain");
+        assert_eq!(strip_ansi("no escapes"), "no escapes");
+    }
+
+    #[test]
+    fn test_basic_colors() {
+        let default = egui::Color32::WHITE;
+        let job = ansi_to_layout_job("a\u{1b}[31mb\u{1b}[39mc", egui::FontId::monospace(12.0), default);
+        assert_eq!(
+            sections(&job),
+            vec![("a", default), ("b", ANSI_PALETTE[1]), ("c", default)]
+        );
+    }
+
+    #[test]
+    fn test_extended_colors() {
+        let default = egui::Color32::WHITE;
+        let job = ansi_to_layout_job(
+            "\u{1b}[38;2;10;20;30mx\u{1b}[38;5;196my\u{1b}[48;5;232mz",
+            egui::FontId::monospace(12.0),
+            default,
+        );
+        let parts = sections(&job);
+        assert_eq!(parts[0], ("x", egui::Color32::from_rgb(10, 20, 30)));
+        assert_eq!(parts[1], ("y", egui::Color32::from_rgb(255, 0, 0)));
+        assert_eq!(job.sections[2].format.background, egui::Color32::from_rgb(8, 8, 8));
+    }
+
+    #[test]
+    fn test_underline_and_reset() {
+        let job = ansi_to_layout_job("\u{1b}[4mu\u{1b}[0mn", egui::FontId::monospace(12.0), egui::Color32::WHITE);
+        assert_ne!(job.sections[0].format.underline, egui::Stroke::NONE);
+        assert_eq!(job.sections[1].format.underline, egui::Stroke::NONE);
+    }
+}
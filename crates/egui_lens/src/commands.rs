@@ -0,0 +1,176 @@
+//! Command line of the logger panel.
+//!
+//! With `LoggerConfig::command_line` enabled, the panel shows an input
+//! line below the table. Commands are looked up in the `CommandRegistry`
+//! of the logger state, which comes with `help`, `clear` and `filter`;
+//! applications register their own. The input and the output of every
+//! command are added to the log as COMMAND entries.
+use std::collections::BTreeMap;
+use std::sync::Arc;
+
+use crate::logger::ReactiveEventLoggerState;
+
+/// Maximum number of entries kept in the command history
+pub const MAX_HISTORY: usize = 100;
+
+/// Start of the COMMAND entry reporting an unknown or failed command
+pub const COMMAND_ERROR_MARKER: &str = "✖ ";
+
+/// Handler of a command, called with the arguments after the command name.
+/// The returned text is added to the log; an error is added as well,
+/// marked with `COMMAND_ERROR_MARKER`.
+pub type CommandHandler = Arc<dyn Fn(&[&str], &mut ReactiveEventLoggerState) -> Result<String, String> + Send + Sync>;
+
+/// Command
+///
+/// A registered command of the command line.
+#[derive(Clone)]
+pub struct Command {
+    /// Name typed to run the command
+    pub name: String,
+    /// One-line description shown by `help`
+    pub help: String,
+    /// What the command does
+    pub handler: CommandHandler,
+}
+
+impl Command {
+    /// Set the description shown by `help`
+    pub fn help(&mut self, help: &str) -> &mut Self {
+        self.help = help.to_string();
+        self
+    }
+}
+
+/// CommandRegistry
+///
+/// The commands available in the command line, by name.
+#[derive(Clone)]
+pub struct CommandRegistry {
+    commands: BTreeMap<String, Command>,
+}
+
+impl Default for CommandRegistry {
+    fn default() -> Self {
+        let mut registry = Self { commands: BTreeMap::new() };
+
+        registry
+            .register("help", |_, state| Ok(state.commands.help_text()))
+            .help("List the available commands");
+
+        registry
+            .register("clear", |_, state| {
+                state.clear_logs();
+                Ok(String::new())
+            })
+            .help("Remove all entries");
+
+        registry
+            .register("filter", |args, state| {
+                state.filter.text_filter = args.join(" ");
+                if args.is_empty() {
+                    Ok("Text filter cleared".to_string())
+                } else {
+                    Ok(format!("Showing entries containing \"{}\"", state.filter.text_filter))
+                }
+            })
+            .help("filter <text>: show only entries containing the text; no text clears the filter");
+
+        registry
+    }
+}
+
+impl CommandRegistry {
+    /// Register a command, replacing a command of the same name.
+    ///
+    /// `registry.register("ping", |args, _| Ok(format!("pong {}", args.join(" ")))).help("Answer");`
+    pub fn register(
+        &mut self,
+        name: &str,
+        handler: impl Fn(&[&str], &mut ReactiveEventLoggerState) -> Result<String, String> + Send + Sync + 'static,
+    ) -> &mut Command {
+        let command = Command {
+            name: name.to_string(),
+            help: String::new(),
+            handler: Arc::new(handler),
+        };
+        self.commands.insert(name.to_string(), command);
+        self.commands.get_mut(name).unwrap()
+    }
+
+    /// Remove a command, returning whether it was registered
+    pub fn unregister(&mut self, name: &str) -> bool {
+        self.commands.remove(name).is_some()
+    }
+
+    /// Look up a command by name
+    pub fn get(&self, name: &str) -> Option<&Command> {
+        self.commands.get(name)
+    }
+
+    /// Names of all commands, sorted
+    pub fn names(&self) -> impl Iterator<Item = &str> {
+        self.commands.keys().map(String::as_str)
+    }
+
+    /// Text listing all commands and their descriptions
+    pub fn help_text(&self) -> String {
+        let width = self.commands.keys().map(|name| name.chars().count()).max().unwrap_or(0);
+        self.commands
+            .values()
+            .map(|command| format!("{:width$}  {}", command.name, command.help, width = width))
+            .collect::<Vec<_>>()
+            .join("\n")
+    }
+
+    /// Complete the command name of an input line.
+    ///
+    /// Returns the completed input and the candidates. A single candidate
+    /// is completed with a trailing space; several are completed to their
+    /// common prefix. Input that already contains arguments is returned as is.
+    pub fn complete(&self, input: &str) -> (String, Vec<String>) {
+        let prefix = input.trim_start();
+        if prefix.contains(char::is_whitespace) {
+            return (input.to_string(), Vec::new());
+        }
+
+        let candidates: Vec<String> = self
+            .names()
+            .filter(|name| name.starts_with(prefix))
+            .map(str::to_string)
+            .collect();
+        let completed = match candidates.as_slice() {
+            [] => input.to_string(),
+            [single] => format!("{} ", single),
+            [first, rest @ ..] => rest.iter().fold(first.clone(), |common, name| {
+                common
+                    .chars()
+                    .zip(name.chars())
+                    .take_while(|(a, b)| a == b)
+                    .map(|(a, _)| a)
+                    .collect()
+            }),
+        };
+        (completed, candidates)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_complete() {
+        let mut registry = CommandRegistry::default();
+        registry.register("format", |_, _| Ok(String::new()));
+
+        assert_eq!(registry.complete("he"), ("help ".to_string(), vec!["help".to_string()]));
+        assert_eq!(
+            registry.complete("f"),
+            ("f".to_string(), vec!["filter".to_string(), "format".to_string()])
+        );
+        assert_eq!(registry.complete("fo").0, "format ");
+        assert_eq!(registry.complete("filter err").0, "filter err");
+        assert!(registry.complete("x").1.is_empty());
+    }
+}
@@ -0,0 +1,232 @@
+//! Builder-style configuration of the logger panel.
+//!
+//! `LoggerConfig` collects the settings that used to be constants: the
+//! toolbar buttons, the column widths, the row order, the buffer size and
+//! the look of the table rows, and enables the command line. It is handed to
+//! `ReactiveEventLogger::config` and can be saved and loaded with serde.
+use serde::{Deserialize, Serialize};
+
+use crate::logger::RowOrder;
+
+/// ToolbarButton
+///
+/// Built-in button of the logger toolbar. Applications add their own
+/// buttons with `ReactiveEventLoggerState::add_toolbar_action`.
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq)]
+pub enum ToolbarButton {
+    /// Drop-down of the saved filter presets
+    Presets,
+    /// Open the filter dialog
+    Filters,
+    /// Open the color settings
+    Colors,
+    /// Save the logs to a file
+    Save,
+    /// Remove all entries
+    Clear,
+    /// Move the logger into a separate window and back
+    PopOut,
+}
+
+impl ToolbarButton {
+    /// All built-in buttons, in their default order from left to right
+    pub const ALL: [ToolbarButton; 6] = [
+        ToolbarButton::Presets,
+        ToolbarButton::Filters,
+        ToolbarButton::Colors,
+        ToolbarButton::Save,
+        ToolbarButton::Clear,
+        ToolbarButton::PopOut,
+    ];
+}
+
+/// LogColumns
+///
+/// Initially visible columns of the log table and their widths.
+/// The message column takes the remaining width.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+#[serde(default)]
+pub struct LogColumns {
+    /// Show the timestamp column
+    pub timestamps: bool,
+    /// Show the log level column
+    pub log_level: bool,
+    /// Show the message column
+    pub messages: bool,
+    /// Width of the timestamp column in points
+    pub timestamp_width: f32,
+    /// Width of the log level column in points
+    pub level_width: f32,
+}
+
+impl Default for LogColumns {
+    fn default() -> Self {
+        Self {
+            timestamps: true,
+            log_level: true,
+            messages: true,
+            timestamp_width: 190.0,
+            level_width: 100.0,
+        }
+    }
+}
+
+/// LogFont
+///
+/// Font of the log table rows.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct LogFont {
+    /// Size in points
+    pub size: f32,
+    /// Monospace or proportional font
+    pub monospace: bool,
+}
+
+impl LogFont {
+    /// Monospace font of the given size
+    pub fn monospace(size: f32) -> Self {
+        Self { size, monospace: true }
+    }
+
+    /// Proportional font of the given size
+    pub fn proportional(size: f32) -> Self {
+        Self { size, monospace: false }
+    }
+
+    /// The egui font
+    pub fn font_id(&self) -> egui::FontId {
+        if self.monospace {
+            egui::FontId::monospace(self.size)
+        } else {
+            egui::FontId::proportional(self.size)
+        }
+    }
+}
+
+/// LoggerConfig
+///
+/// Configuration of a logger panel, built like
+/// `LoggerConfig::new().order(RowOrder::OldestFirst).max_logs(5000)`.
+/// Missing fields take their defaults when a config is deserialized.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+#[serde(default)]
+pub struct LoggerConfig {
+    /// Built-in toolbar buttons, from left to right
+    pub toolbar: Vec<ToolbarButton>,
+    /// Initially visible columns and their widths
+    pub columns: LogColumns,
+    /// Initial order of the table rows
+    pub order: RowOrder,
+    /// Maximum number of entries kept in the buffer
+    pub max_logs: usize,
+    /// Font of the table rows, or None for the monospace text style
+    pub font: Option<LogFont>,
+    /// Height of a table row in points
+    pub row_height: f32,
+    /// Alternate the background of the table rows
+    pub striped: bool,
+    /// Show the command line below the table
+    pub command_line: bool,
+}
+
+impl Default for LoggerConfig {
+    fn default() -> Self {
+        Self {
+            toolbar: ToolbarButton::ALL.to_vec(),
+            columns: LogColumns::default(),
+            order: RowOrder::default(),
+            max_logs: 1000,
+            font: None,
+            row_height: 20.0,
+            striped: true,
+            command_line: false,
+        }
+    }
+}
+
+impl LoggerConfig {
+    /// Start from the default configuration
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Set the built-in toolbar buttons, from left to right
+    pub fn toolbar(mut self, buttons: impl IntoIterator<Item = ToolbarButton>) -> Self {
+        self.toolbar = buttons.into_iter().collect();
+        self
+    }
+
+    /// Set the visible columns and their widths
+    pub fn columns(mut self, columns: LogColumns) -> Self {
+        self.columns = columns;
+        self
+    }
+
+    /// Set the order of the table rows
+    pub fn order(mut self, order: RowOrder) -> Self {
+        self.order = order;
+        self
+    }
+
+    /// Set the maximum number of entries kept in the buffer, at least 1
+    pub fn max_logs(mut self, max_logs: usize) -> Self {
+        self.max_logs = max_logs.max(1);
+        self
+    }
+
+    /// The config with `max_logs` raised to at least 1, e.g. after it was
+    /// deserialized or built as a struct literal
+    pub(crate) fn clamped(mut self) -> Self {
+        self.max_logs = self.max_logs.max(1);
+        self
+    }
+
+    /// Set the font of the table rows
+    pub fn font(mut self, font: LogFont) -> Self {
+        self.font = Some(font);
+        self
+    }
+
+    /// Set the height of a table row
+    pub fn row_height(mut self, row_height: f32) -> Self {
+        self.row_height = row_height;
+        self
+    }
+
+    /// Alternate the background of the table rows, or not
+    pub fn striped(mut self, striped: bool) -> Self {
+        self.striped = striped;
+        self
+    }
+
+    /// Show the command line below the table, or not
+    pub fn command_line(mut self, command_line: bool) -> Self {
+        self.command_line = command_line;
+        self
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_config_serde() {
+        let config = LoggerConfig::new()
+            .toolbar([ToolbarButton::Filters, ToolbarButton::Clear])
+            .order(RowOrder::OldestFirst)
+            .max_logs(5000)
+            .font(LogFont::proportional(14.0))
+            .striped(false);
+
+        let json = serde_json::to_string(&config).unwrap();
+        assert_eq!(serde_json::from_str::<LoggerConfig>(&json).unwrap(), config);
+
+        // Missing fields take their defaults
+        let partial: LoggerConfig = serde_json::from_str(r#"{"max_logs": 200, "columns": {"level_width": 80.0}}"#).unwrap();
+        assert_eq!(partial.max_logs, 200);
+        assert_eq!(partial.columns.level_width, 80.0);
+        assert_eq!(partial.columns.timestamp_width, 190.0);
+        assert_eq!(partial.toolbar, ToolbarButton::ALL.to_vec());
+    }
+}
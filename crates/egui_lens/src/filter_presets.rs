@@ -0,0 +1,162 @@
+//! Named filter presets.
+//!
+//! A preset stores a complete `LogFilter` under a name ("errors only",
+//! "network debugging"), so switching between common views is a single
+//! click in the presets dropdown next to the Filters button. Presets are
+//! saved as JSON next to the logger colors in the config directory.
+use std::fs;
+use std::path::{Path, PathBuf};
+
+use crate::logger::LogFilter;
+
+/// FilterPreset
+///
+/// A `LogFilter` saved under a name.
+#[derive(Debug, Clone, serde::Serialize, serde::Deserialize, PartialEq)]
+pub struct FilterPreset {
+    pub name: String,
+    pub filter: LogFilter,
+}
+
+/// FilterPresets
+///
+/// The list of saved filter presets.
+#[derive(Debug, Clone, serde::Serialize, serde::Deserialize, PartialEq)]
+pub struct FilterPresets {
+    pub presets: Vec<FilterPreset>,
+}
+
+impl Default for FilterPresets {
+    fn default() -> Self {
+        let errors_and_warnings = LogFilter {
+            show_info: false,
+            show_debug: false,
+            show_custom: false,
+            show_system: false,
+            ..LogFilter::default()
+        };
+
+        Self {
+            presets: vec![
+                FilterPreset { name: "Everything".to_string(), filter: LogFilter::default() },
+                FilterPreset { name: "Errors and warnings".to_string(), filter: errors_and_warnings },
+            ],
+        }
+    }
+}
+
+impl FilterPresets {
+    /// Get a preset by name
+    pub fn get(&self, name: &str) -> Option<&FilterPreset> {
+        self.presets.iter().find(|preset| preset.name == name)
+    }
+
+    /// Save a filter under a name, replacing an existing preset of that name
+    pub fn save_preset(&mut self, name: &str, filter: LogFilter) {
+        match self.presets.iter_mut().find(|preset| preset.name == name) {
+            Some(preset) => preset.filter = filter,
+            None => self.presets.push(FilterPreset { name: name.to_string(), filter }),
+        }
+    }
+
+    /// Rename a preset. Fails if the new name is empty or already taken.
+    pub fn rename(&mut self, name: &str, new_name: &str) -> bool {
+        let new_name = new_name.trim();
+        if new_name.is_empty() || (new_name != name && self.get(new_name).is_some()) {
+            return false;
+        }
+
+        match self.presets.iter_mut().find(|preset| preset.name == name) {
+            Some(preset) => {
+                preset.name = new_name.to_string();
+                true
+            }
+            None => false,
+        }
+    }
+
+    /// Delete a preset
+    pub fn remove(&mut self, name: &str) {
+        self.presets.retain(|preset| preset.name != name);
+    }
+
+    /// Path of the presets file in the config directory
+    pub fn config_path() -> PathBuf {
+        dirs::config_dir()
+            .unwrap_or_else(|| PathBuf::from("."))
+            .join("egui_mobius_template")
+            .join("log_filter_presets.json")
+    }
+
+    /// Load the presets from the config directory, or the default presets
+    pub fn load() -> Self {
+        Self::load_from(&Self::config_path())
+    }
+
+    /// Load the presets from a file, or the default presets
+    pub fn load_from(path: &Path) -> Self {
+        match fs::read_to_string(path) {
+            Ok(file_content) => serde_json::from_str(&file_content).unwrap_or_else(|e| {
+                eprintln!("Failed to parse filter presets JSON: {}", e);
+                Self::default()
+            }),
+            Err(_) => Self::default(),
+        }
+    }
+
+    /// Save the presets to the config directory (in the background)
+    pub fn save(&self) {
+        let presets = self.clone();
+        std::thread::spawn(move || {
+            if let Err(e) = presets.save_to(&Self::config_path()) {
+                eprintln!("Failed to save filter presets: {}", e);
+            }
+        });
+    }
+
+    /// Save the presets to a file
+    pub fn save_to(&self, path: &Path) -> std::io::Result<()> {
+        if let Some(dir) = path.parent() {
+            fs::create_dir_all(dir)?;
+        }
+        let json = serde_json::to_string_pretty(self).map_err(std::io::Error::other)?;
+        fs::write(path, json)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_preset_management() {
+        let mut presets = FilterPresets::default();
+        let network = LogFilter {
+            only_custom_type: Some("network".to_string()),
+            ..LogFilter::default()
+        };
+
+        presets.save_preset("Network", network.clone());
+        assert_eq!(presets.get("Network").map(|preset| &preset.filter), Some(&network));
+
+        assert!(!presets.rename("Network", "Everything"), "names are unique");
+        assert!(!presets.rename("Network", "  "));
+        assert!(presets.rename("Network", "Network debugging"));
+        assert!(presets.get("Network").is_none());
+
+        presets.remove("Network debugging");
+        assert_eq!(presets, FilterPresets::default());
+    }
+
+    #[test]
+    fn test_presets_round_trip() {
+        let path = std::env::temp_dir().join(format!("egui_lens_presets_test_{}.json", std::process::id()));
+        let mut presets = FilterPresets::default();
+        presets.save_preset("Database", LogFilter { text_filter: "query".to_string(), ..LogFilter::default() });
+
+        presets.save_to(&path).unwrap();
+        assert_eq!(FilterPresets::load_from(&path), presets);
+
+        let _ = fs::remove_file(path);
+    }
+}
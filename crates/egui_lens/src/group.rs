@@ -0,0 +1,88 @@
+//! Scoped operation groups.
+//!
+//! `ReactiveEventLogger::group` adds a header entry and returns a
+//! `GroupGuard`. Entries logged from the same thread while the guard is
+//! alive are nested under the header, which the table shows as a collapsible
+//! row. When the guard
+//! is dropped the header records the total duration and takes on the worst
+//! severity of its entries. Groups can be nested.
+use std::time::{Duration, Instant};
+
+use egui_mobius_reactive::{Dynamic, ReactiveWidgetRef};
+
+use crate::logger::ReactiveEventLoggerState;
+
+/// LogGroup
+///
+/// Attached to the header entry of an operation group.
+#[derive(Clone, Debug, PartialEq)]
+pub struct LogGroup {
+    /// When the group was opened
+    pub started_at: Instant,
+    /// Total duration, once the group is closed
+    pub duration: Option<Duration>,
+    /// Level of the most severe entry in the group, once it is closed
+    pub worst_level: Option<String>,
+}
+
+impl LogGroup {
+    /// Start a group now
+    pub fn new() -> Self {
+        Self {
+            started_at: Instant::now(),
+            duration: None,
+            worst_level: None,
+        }
+    }
+}
+
+impl Default for LogGroup {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// GroupGuard
+///
+/// Keeps an operation group open, returned by `ReactiveEventLogger::group`.
+/// Dropping the guard closes the group. While it is open, entries added to
+/// the logger state from the thread that opened it are nested under the
+/// group; entries from other threads are not.
+pub struct GroupGuard {
+    state: Dynamic<ReactiveEventLoggerState>,
+    sequence: u64,
+}
+
+impl GroupGuard {
+    pub(crate) fn new(state: Dynamic<ReactiveEventLoggerState>, sequence: u64) -> Self {
+        Self { state, sequence }
+    }
+
+    /// Sequence number of the group's header entry
+    pub fn sequence(&self) -> u64 {
+        self.sequence
+    }
+}
+
+impl Drop for GroupGuard {
+    fn drop(&mut self) {
+        if let Some(arc) = ReactiveWidgetRef::from_dynamic(&self.state).weak_ref.upgrade() {
+            let hooks = {
+                let mut state = arc.lock().unwrap();
+                state.end_group(self.sequence);
+                state.take_pending_hooks()
+            };
+            hooks.run();
+        }
+    }
+}
+
+/// Rank of a level text for finding the worst severity in a group
+pub fn severity(level: &str) -> u8 {
+    match level {
+        "ERROR" => 3,
+        "WARNING" => 2,
+        "DEBUG" | "" => 0,
+        _ => 1,
+    }
+}
@@ -0,0 +1,157 @@
+//! Callbacks on log ingestion.
+//!
+//! Hooks registered with `ReactiveEventLoggerState::on_entry`, `on_level`
+//! and `on_match` are called for every entry stored in the buffer, so an
+//! application can flash a tab or update a status bar counter without
+//! diffing the state each frame. Entries dropped by a rate limit don't
+//! reach the hooks; an entry merged into a duplicate row does, with the
+//! merged row.
+//!
+//! A merged row is passed with its `repeat_count` above 1, so a hook can
+//! skip repeats. A live progress entry is passed again once it finishes as
+//! an info or error entry.
+//!
+//! Hooks don't run while the logger state is locked. The state queues the
+//! calls and `ReactiveEventLogger` runs them once it has released the lock,
+//! so a hook may log to the same state. Code that adds entries to the state
+//! directly runs the queued calls with `take_pending_hooks` after unlocking;
+//! otherwise the next frame of the logger panel runs them. While nobody runs
+//! them, the queue keeps the newest `MAX_PENDING_HOOKS` calls and counts the
+//! dropped ones.
+use std::collections::VecDeque;
+use std::sync::Arc;
+
+use regex::Regex;
+
+use crate::logger::LogType;
+use crate::payload::LoggerPayload;
+
+/// Maximum number of hook calls queued until they are run
+pub const MAX_PENDING_HOOKS: usize = 1000;
+
+/// Callback of a hook
+pub type LogHookCallback = Arc<dyn Fn(&LoggerPayload) + Send + Sync>;
+
+/// HookId
+///
+/// Identifies a registered hook, for removing it again.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct HookId(u64);
+
+/// HookTrigger
+///
+/// Which entries a hook is called for.
+#[derive(Clone)]
+pub enum HookTrigger {
+    /// Every entry
+    Entry,
+    /// Entries of a log level or custom type
+    Level(LogType),
+    /// Entries whose message matches a regular expression
+    Match(Regex),
+}
+
+impl HookTrigger {
+    /// Check if the trigger applies to a log entry
+    pub fn matches(&self, log: &LoggerPayload) -> bool {
+        match self {
+            HookTrigger::Entry => true,
+            HookTrigger::Level(level) => level.matches(log),
+            HookTrigger::Match(regex) => regex.is_match(&log.log_message.content.value),
+        }
+    }
+}
+
+#[derive(Clone)]
+struct Hook {
+    id: HookId,
+    trigger: HookTrigger,
+    callback: LogHookCallback,
+}
+
+/// LogHooks
+///
+/// The hooks registered on a logger state.
+#[derive(Clone, Default)]
+pub struct LogHooks {
+    hooks: Vec<Hook>,
+    next_id: u64,
+    pending: VecDeque<(LogHookCallback, LoggerPayload)>,
+    dropped: u64,
+}
+
+impl LogHooks {
+    /// Register a hook, returning its id
+    pub fn add(&mut self, trigger: HookTrigger, callback: impl Fn(&LoggerPayload) + Send + Sync + 'static) -> HookId {
+        let id = HookId(self.next_id);
+        self.next_id += 1;
+        self.hooks.push(Hook {
+            id,
+            trigger,
+            callback: Arc::new(callback),
+        });
+        id
+    }
+
+    /// Remove a hook, returning whether it was registered
+    pub fn remove(&mut self, id: HookId) -> bool {
+        let count = self.hooks.len();
+        self.hooks.retain(|hook| hook.id != id);
+        self.hooks.len() != count
+    }
+
+    /// Number of registered hooks
+    pub fn len(&self) -> usize {
+        self.hooks.len()
+    }
+
+    /// Check if no hooks are registered
+    pub fn is_empty(&self) -> bool {
+        self.hooks.is_empty()
+    }
+
+    /// Number of queued calls dropped because the queue was full
+    pub fn dropped(&self) -> u64 {
+        self.dropped
+    }
+
+    /// Queue calls of the hooks whose trigger applies to an ingested entry,
+    /// dropping the oldest calls beyond `MAX_PENDING_HOOKS`
+    pub(crate) fn dispatch(&mut self, log: &LoggerPayload) {
+        for hook in &self.hooks {
+            if hook.trigger.matches(log) {
+                if self.pending.len() == MAX_PENDING_HOOKS {
+                    self.pending.pop_front();
+                    self.dropped += 1;
+                }
+                self.pending.push_back((hook.callback.clone(), log.clone()));
+            }
+        }
+    }
+
+    /// Take the queued calls, to run them after the state is unlocked
+    pub(crate) fn take_pending(&mut self) -> PendingHooks {
+        PendingHooks(std::mem::take(&mut self.pending).into())
+    }
+}
+
+/// PendingHooks
+///
+/// Hook calls queued while the logger state was locked, returned by
+/// `ReactiveEventLoggerState::take_pending_hooks`.
+#[must_use = "the hooks are only called by `run`"]
+pub struct PendingHooks(Vec<(LogHookCallback, LoggerPayload)>);
+
+impl PendingHooks {
+    /// Call the hooks, in the order the entries arrived
+    pub fn run(self) {
+        for (callback, log) in self.0 {
+            callback(&log);
+        }
+    }
+
+    /// Check if no calls are queued
+    pub fn is_empty(&self) -> bool {
+        self.0.is_empty()
+    }
+}
@@ -0,0 +1,269 @@
+//! JSON message rendering.
+//!
+//! Many log messages are JSON payloads (HTTP bodies, configuration dumps).
+//! This module detects such messages and renders them either as a compact,
+//! syntax colored single line for the log table, or as a collapsible tree
+//! for the detail pane. Syntax colors are taken from `LogColors`. Parsed
+//! messages are kept in a `JsonCache` by sequence number, so a row is
+//! parsed once and not in every frame.
+use std::collections::HashMap;
+use std::sync::{Arc, Mutex};
+
+use eframe::egui;
+use egui::text::{LayoutJob, TextFormat};
+use serde_json::Value;
+
+use crate::logger_colors::LogColors;
+
+/// Maximum number of characters shown in a single-line JSON preview
+const PREVIEW_MAX_CHARS: usize = 240;
+
+/// Parse a message as JSON if it looks like a JSON object or array.
+///
+/// Scalars ("42", "true") are deliberately not treated as JSON, since
+/// plain messages like these are far more common than JSON documents.
+pub fn parse_json_message(message: &str) -> Option<Value> {
+    let trimmed = message.trim();
+    let looks_like_json = (trimmed.starts_with('{') && trimmed.ends_with('}'))
+        || (trimmed.starts_with('[') && trimmed.ends_with(']'));
+
+    if !looks_like_json {
+        return None;
+    }
+
+    serde_json::from_str(trimmed).ok()
+}
+
+/// JsonCache
+///
+/// Messages parsed by `parse_json_message`, by the sequence number of
+/// their entry. Clones share the cache, so it can be kept in egui's temp
+/// data and used from every frame.
+#[derive(Clone, Default)]
+pub(crate) struct JsonCache(Arc<Mutex<JsonCacheEntries>>);
+
+#[derive(Default)]
+struct JsonCacheEntries {
+    /// Edit count of the logger state the values were parsed from
+    edits: u64,
+    values: HashMap<u64, Option<Arc<Value>>>,
+}
+
+impl JsonCache {
+    /// Forget entries that were evicted (below `first_sequence`), or all of
+    /// them if stored entries were changed in place since the last call
+    pub(crate) fn sync(&self, first_sequence: u64, log_count: usize, edits: u64) {
+        let mut entries = self.0.lock().unwrap();
+        if entries.edits != edits {
+            entries.edits = edits;
+            entries.values.clear();
+        } else if entries.values.len() > log_count {
+            entries.values.retain(|sequence, _| *sequence >= first_sequence);
+        }
+    }
+
+    /// The parsed message of an entry, parsing it on first use
+    pub(crate) fn get(&self, sequence: u64, message: &str) -> Option<Arc<Value>> {
+        self.0.lock().unwrap()
+            .values
+            .entry(sequence)
+            .or_insert_with(|| parse_json_message(message).map(Arc::new))
+            .clone()
+    }
+}
+
+/// Appends colored JSON text to a LayoutJob, keeping track of the length
+struct JsonWriter<'a> {
+    job: LayoutJob,
+    font_id: egui::FontId,
+    colors: &'a LogColors,
+    punctuation: egui::Color32,
+    chars: usize,
+    max_chars: usize,
+    truncated: bool,
+}
+
+impl JsonWriter<'_> {
+    fn push(&mut self, text: &str, color: egui::Color32) {
+        if self.truncated {
+            return;
+        }
+
+        let count = text.chars().count();
+        if self.chars + count > self.max_chars {
+            // Cut the text and mark the preview as truncated
+            let cut: String = text.chars().take(self.max_chars - self.chars).collect();
+            self.job.append(&format!("{}…", cut), 0.0, TextFormat::simple(self.font_id.clone(), color));
+            self.truncated = true;
+        } else {
+            self.job.append(text, 0.0, TextFormat::simple(self.font_id.clone(), color));
+            self.chars += count;
+        }
+    }
+
+    fn write_value(&mut self, value: &Value) {
+        match value {
+            Value::Object(map) => {
+                self.push("{", self.punctuation);
+                for (index, (key, value)) in map.iter().enumerate() {
+                    if index > 0 {
+                        self.push(",", self.punctuation);
+                    }
+                    self.push(&Value::String(key.clone()).to_string(), self.colors.json_key);
+                    self.push(":", self.punctuation);
+                    self.write_value(value);
+                }
+                self.push("}", self.punctuation);
+            }
+            Value::Array(items) => {
+                self.push("[", self.punctuation);
+                for (index, item) in items.iter().enumerate() {
+                    if index > 0 {
+                        self.push(",", self.punctuation);
+                    }
+                    self.write_value(item);
+                }
+                self.push("]", self.punctuation);
+            }
+            scalar => {
+                let color = scalar_color(scalar, self.colors);
+                self.push(&scalar.to_string(), color);
+            }
+        }
+    }
+}
+
+/// Get the syntax color of a scalar JSON value
+fn scalar_color(value: &Value, colors: &LogColors) -> egui::Color32 {
+    match value {
+        Value::String(_) => colors.json_string,
+        Value::Number(_) => colors.json_number,
+        _ => colors.json_literal,
+    }
+}
+
+/// Build a compact single-line, syntax colored preview of a JSON value.
+///
+/// Punctuation uses `punctuation_color` (normally the message color of the
+/// log level) and long documents are cut off with an ellipsis.
+pub fn json_preview_job(value: &Value, font_id: egui::FontId, colors: &LogColors, punctuation_color: egui::Color32) -> LayoutJob {
+    let mut writer = JsonWriter {
+        job: LayoutJob::default(),
+        font_id,
+        colors,
+        punctuation: punctuation_color,
+        chars: 0,
+        max_chars: PREVIEW_MAX_CHARS,
+        truncated: false,
+    };
+    writer.write_value(value);
+    writer.job
+}
+
+/// Text of the single-line preview of a JSON value, as searched and
+/// highlighted in the log table
+pub fn json_preview_text(value: &Value) -> String {
+    json_preview_job(value, egui::FontId::default(), &LogColors::default(), egui::Color32::PLACEHOLDER).text
+}
+
+/// Display a JSON value as a collapsible tree.
+///
+/// Objects and arrays become collapsing headers (the first two levels are
+/// open by default), scalars are shown as colored `key: value` lines.
+pub fn show_json_tree(ui: &mut egui::Ui, value: &Value, colors: &LogColors, id_salt: impl std::hash::Hash) {
+    let id = egui::Id::new(id_salt);
+    ui.push_id(id, |ui| {
+        show_json_node(ui, None, value, colors, id, 0);
+    });
+}
+
+fn show_json_node(ui: &mut egui::Ui, key: Option<&str>, value: &Value, colors: &LogColors, id: egui::Id, depth: usize) {
+    let font_id = egui::TextStyle::Monospace.resolve(ui.style());
+    let punctuation = ui.visuals().text_color();
+
+    // Build the "key: " prefix shared by all node kinds
+    let mut job = LayoutJob::default();
+    if let Some(key) = key {
+        job.append(key, 0.0, TextFormat::simple(font_id.clone(), colors.json_key));
+        job.append(": ", 0.0, TextFormat::simple(font_id.clone(), punctuation));
+    }
+
+    match value {
+        Value::Object(map) => {
+            let summary = format!("{{…}} {} {}", map.len(), if map.len() == 1 { "key" } else { "keys" });
+            job.append(&summary, 0.0, TextFormat::simple(font_id, ui.visuals().weak_text_color()));
+            egui::CollapsingHeader::new(job)
+                .id_salt(id)
+                .default_open(depth < 2)
+                .show(ui, |ui| {
+                    for (child_key, child) in map {
+                        show_json_node(ui, Some(child_key), child, colors, id.with(child_key), depth + 1);
+                    }
+                });
+        }
+        Value::Array(items) => {
+            let summary = format!("[…] {} {}", items.len(), if items.len() == 1 { "item" } else { "items" });
+            job.append(&summary, 0.0, TextFormat::simple(font_id, ui.visuals().weak_text_color()));
+            egui::CollapsingHeader::new(job)
+                .id_salt(id)
+                .default_open(depth < 2)
+                .show(ui, |ui| {
+                    for (index, child) in items.iter().enumerate() {
+                        let child_key = index.to_string();
+                        show_json_node(ui, Some(&child_key), child, colors, id.with(index), depth + 1);
+                    }
+                });
+        }
+        scalar => {
+            job.append(&scalar.to_string(), 0.0, TextFormat::simple(font_id, scalar_color(scalar, colors)));
+            ui.add(egui::Label::new(job).wrap());
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_json_message() {
+        assert!(parse_json_message(r#"{"status": 200, "ok": true}"#).is_some());
+        assert!(parse_json_message("  [1, 2, 3]\n").is_some());
+        assert!(parse_json_message("42").is_none());
+        assert!(parse_json_message("{not json}").is_none());
+        assert!(parse_json_message("[INFO] started").is_none());
+    }
+
+    #[test]
+    fn test_json_preview_is_compact_and_truncated() {
+        let colors = LogColors::default();
+        let font_id = egui::FontId::monospace(12.0);
+
+        let value = parse_json_message("{\n  \"a\": [1, 2],\n  \"b\": null\n}").unwrap();
+        let job = json_preview_job(&value, font_id.clone(), &colors, egui::Color32::WHITE);
+        assert_eq!(job.text, r#"{"a":[1,2],"b":null}"#);
+
+        let long = Value::String("x".repeat(1000));
+        let job = json_preview_job(&long, font_id, &colors, egui::Color32::WHITE);
+        assert_eq!(job.text.chars().count(), PREVIEW_MAX_CHARS + 1);
+        assert!(job.text.ends_with('…'));
+    }
+
+    #[test]
+    fn test_json_cache_parses_each_entry_once() {
+        let cache = JsonCache::default();
+        let first = cache.get(1, r#"{"a": 1}"#).unwrap();
+        assert!(cache.get(2, "plain text").is_none());
+
+        // Clones share the parsed values, later calls don't parse again
+        let shared = cache.clone();
+        assert!(Arc::ptr_eq(&first, &shared.get(1, r#"{"a": 1}"#).unwrap()));
+
+        // Evicted entries are dropped, edits in place invalidate everything
+        cache.sync(2, 1, 0);
+        assert!(!cache.0.lock().unwrap().values.contains_key(&1));
+        assert!(cache.0.lock().unwrap().values.contains_key(&2));
+        cache.sync(2, 1, 1);
+        assert!(cache.0.lock().unwrap().values.is_empty());
+    }
+}
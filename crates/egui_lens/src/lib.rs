@@ -12,17 +12,70 @@
 //! - Flexible custom log types with string identifiers
 //! - Configurable UI with column visibility options
 //! - Export logs to file functionality
+//! - ANSI color escape sequence rendering
+//! - Pretty-printing of JSON messages
+//! - Rate limiting of log floods with suppression summaries
+//! - Per-level retention rules by count and age
+//! - Memory budget with truncation of oversized messages
+//! - Search with inline highlighting and match navigation
+//! - Named filter presets saved to disk
+//! - Bookmarks and notes on log entries, kept in JSON exports and sessions
+//! - Live progress entries with an inline progress bar and ETA
+//! - Collapsible, nested operation groups with duration and worst severity
+//! - Named channels shown as tabs with unread and error badges
+//! - Split view comparing two filtered streams with synchronized scrolling
+//! - Pop-out into a separate window that docks back on close
+//! - Builder-style, serializable panel configuration
+//! - Application buttons and menus in the toolbar
+//! - Hooks called on ingested entries, by level or message pattern
+//! - Alert rules with toast notifications and a notification center
+//! - Unread warning and error badges for dock tab titles
+//! - Command line with registrable commands, history and completion
 //! - Reactive architecture using egui_mobius_reactive
 
 mod logger;
 mod payload;
 mod logger_colors;
+mod ansi;
+mod json_view;
+mod rate_limit;
+mod retention;
+mod memory;
+mod search;
+mod filter_presets;
+mod progress;
+mod group;
+mod config;
+mod toolbar;
+mod hooks;
+mod alerts;
+mod commands;
 
 pub use logger::{
     ReactiveEventLogger,
     ReactiveEventLoggerState,
     LogType,
+    LogFilter,
+    DetailPane,
+    Deduplication,
+    RowOrder,
+    SplitView,
+    UnreadCounts,
 };
 
 pub use logger_colors::{LogColors, Color32Wrapper};
-pub use payload::LoggerPayload;
\ No newline at end of file
+pub use payload::LoggerPayload;
+pub use ansi::{ansi_to_layout_job, strip_ansi, contains_ansi};
+pub use json_view::{parse_json_message, json_preview_job, show_json_tree};
+pub use rate_limit::{RateLimiter, RateLimit, RateLimitScope, RateLimitMetrics};
+pub use retention::RetentionRule;
+pub use memory::{MemoryBudget, read_archive};
+pub use search::{LogSearch, SearchMatcher};
+pub use filter_presets::{FilterPreset, FilterPresets};
+pub use progress::{Progress, ProgressHandle};
+pub use group::{GroupGuard, LogGroup};
+pub use config::{LoggerConfig, LogColumns, LogFont, ToolbarButton};
+pub use toolbar::{ToolbarAction, ToolbarActionKind, ToolbarCallback, ToolbarMenuCallback};
+pub use hooks::{HookId, HookTrigger, LogHooks, LogHookCallback, PendingHooks};
+pub use alerts::{Alert, AlertCondition, AlertRule, Alerts};
+pub use commands::{Command, CommandHandler, CommandRegistry, COMMAND_ERROR_MARKER};
@@ -18,10 +18,27 @@
 //! The filtering options are stored in a `LogFilter` struct, which can be modified by the user.
 //! The logger state is stored in a `ReactiveEventLoggerState` struct, which is shared across the application.
 //!
+use std::collections::{BTreeSet, HashMap, HashSet};
+use std::thread::{self, ThreadId};
+
 use eframe::egui;
 use egui_mobius_reactive::{Dynamic, ReactiveWidgetRef};
 use crate::payload::LoggerPayload;
 use crate::logger_colors::LogColors;
+use crate::ansi;
+use crate::json_view::{self, JsonCache};
+use crate::rate_limit::{RateLimiter, format_count};
+use crate::progress::{Progress, ProgressHandle, format_duration};
+use crate::group::{GroupGuard, LogGroup, severity};
+use crate::retention::{RetentionRule, apply_retention};
+use crate::memory::{MemoryBudget, format_megabytes, read_archive, remove_archive};
+use crate::filter_presets::FilterPresets;
+use crate::config::{LoggerConfig, ToolbarButton};
+use crate::toolbar::ToolbarAction;
+use crate::hooks::{HookId, HookTrigger, LogHooks, PendingHooks};
+use crate::alerts::{Alert, AlertRule, Alerts};
+use crate::commands::{CommandRegistry, COMMAND_ERROR_MARKER, MAX_HISTORY};
+use crate::search::{LogSearch, SearchMatcher, step_hit, MATCH_BACKGROUND, CURRENT_MATCH_BACKGROUND};
 
 /// LogType
 ///
@@ -34,7 +51,11 @@ use crate::logger_colors::LogColors;
 /// 
 /// The Custom variant accepts a String parameter, allowing
 /// for extensible custom log types with arbitrary identifiers.
+///
+/// New types may be added in minor releases, so matches on it need a
+/// wildcard arm.
 #[derive(Clone, PartialEq)]
+#[non_exhaustive]
 pub enum LogType {
     /// Standard info level messages
     Info,
@@ -60,6 +81,8 @@ pub enum LogType {
     Success,
     /// For neutral/default messages
     Default,
+    /// For commands typed in the command line and their output
+    Command,
     /// For custom types with a specific identifier string
     Custom(String),
 }
@@ -69,7 +92,8 @@ pub enum LogType {
 /// Encapsulates filtering options for log messages.
 /// This struct controls which log types are displayed and provides
 /// text-based filtering capabilities.
-#[derive(Clone, Debug)]
+#[derive(Clone, Debug, PartialEq, serde::Serialize, serde::Deserialize)]
+#[serde(default)]
 pub struct LogFilter {
     /// Show/hide INFO logs
     pub show_info: bool,
@@ -85,6 +109,17 @@ pub struct LogFilter {
     pub show_system: bool,
     /// Text filter to search in log messages (case-insensitive)
     pub text_filter: String,
+    /// Restrict custom logs to a single custom type identifier
+    pub only_custom_type: Option<String>,
+    /// Hide messages matching any of these patterns (see `message_pattern`)
+    pub excluded_patterns: Vec<String>,
+    /// Show only bookmarked entries
+    pub only_bookmarked: bool,
+    /// Show only the entries of an operation group (sequence number of its
+    /// header). Sequence numbers are only valid within a session, so this is
+    /// neither persisted nor saved in presets.
+    #[serde(skip)]
+    pub only_group: Option<u64>,
 }
 
 impl Default for LogFilter {
@@ -97,6 +132,10 @@ impl Default for LogFilter {
             show_custom: true,
             show_system: true,
             text_filter: String::new(),
+            only_custom_type: None,
+            excluded_patterns: Vec::new(),
+            only_bookmarked: false,
+            only_group: None,
         }
     }
 }
@@ -112,8 +151,8 @@ impl LogFilter {
         // First check log type filtering
         let passes_type_filter = if !log.log_level.info.value.is_empty() {
             // Check if it's a custom type
-            if log.log_level.info.value.starts_with("CUSTOM:") {
-                self.show_custom
+            if let Some(identifier) = log.log_level.info.value.strip_prefix("CUSTOM:") {
+                self.show_custom && self.only_custom_type.as_deref().is_none_or(|only| only == identifier)
             } else {
                 self.show_info
             }
@@ -133,13 +172,30 @@ impl LogFilter {
             return false;
         }
         
+        if self.only_bookmarked && !log.bookmarked {
+            return false;
+        }
+        
+        if let Some(group) = self.only_group
+            && log.sequence != group
+            && !log.groups.contains(&group) {
+            return false;
+        }
+        
+        // Hide messages the user explicitly excluded
+        if !self.excluded_patterns.is_empty()
+            && self.excluded_patterns.contains(&message_pattern(&log.log_message.content.value)) {
+            return false;
+        }
+        
         // If text filter is empty, all logs pass the text filter
         if self.text_filter.is_empty() {
             return true;
         }
         
-        // Check if the message contains the text filter (case-insensitive)
-        let lowercase_message = log.log_message.content.value.to_lowercase();
+        // Check if the message contains the text filter (case-insensitive),
+        // ignoring any ANSI escape codes embedded in the message
+        let lowercase_message = ansi::strip_ansi(&log.log_message.content.value).to_lowercase();
         let lowercase_filter = self.text_filter.to_lowercase();
         
         lowercase_message.contains(&lowercase_filter)
@@ -150,30 +206,97 @@ impl LogFilter {
         *self = Self::default();
     }
     
+    /// Show only logs of the same type as the given log
+    pub fn show_only_type_of(&mut self, log: &LoggerPayload) {
+        self.show_info = false;
+        self.show_warning = false;
+        self.show_error = false;
+        self.show_debug = false;
+        self.show_custom = false;
+        self.show_system = false;
+        self.only_custom_type = None;
+        
+        if !log.log_level.info.value.is_empty() {
+            if let Some(identifier) = log.log_level.info.value.strip_prefix("CUSTOM:") {
+                self.show_custom = true;
+                self.only_custom_type = Some(identifier.to_string());
+            } else {
+                self.show_info = true;
+            }
+        } else if !log.log_level.warning.value.is_empty() {
+            self.show_warning = true;
+        } else if !log.log_level.error.value.is_empty() {
+            self.show_error = true;
+        } else if !log.log_level.debug.value.is_empty() {
+            self.show_debug = true;
+        } else {
+            self.show_system = true;
+        }
+    }
+    
+    /// Hide all messages similar to the given one
+    pub fn exclude_like(&mut self, message: &str) {
+        let pattern = message_pattern(message);
+        if !self.excluded_patterns.contains(&pattern) {
+            self.excluded_patterns.push(pattern);
+        }
+    }
+    
     /// Save filter state to memory for persistence between sessions
+    #[deprecated(note = "filters saved this way are shared by every logger, use `save_to_memory_with_id`")]
     pub fn save_to_memory(&self, ctx: &egui::Context) {
+        self.save_keys(ctx, egui::Id::new);
+    }
+    
+    /// Load filter state from memory
+    #[deprecated(note = "filters saved this way are shared by every logger, use `load_from_memory_with_id`")]
+    pub fn load_from_memory(&mut self, ctx: &egui::Context) {
+        self.load_keys(ctx, egui::Id::new);
+    }
+    
+    /// Save filter state to memory for persistence between sessions.
+    /// The keys are scoped to the `id_source` of the logger.
+    pub fn save_to_memory_with_id(&self, ctx: &egui::Context, id_source: egui::Id) {
+        self.save_keys(ctx, |key| id_source.with(key));
+    }
+    
+    /// Load filter state saved by `save_to_memory_with_id` under the same
+    /// `id_source`. Settings saved by the deprecated `save_to_memory` are
+    /// picked up as well, so filters stored before loggers had their own
+    /// keys are not lost.
+    pub fn load_from_memory_with_id(&mut self, ctx: &egui::Context, id_source: egui::Id) {
+        self.load_keys(ctx, egui::Id::new);
+        self.load_keys(ctx, |key| id_source.with(key));
+    }
+    
+    fn save_keys(&self, ctx: &egui::Context, key: impl Fn(&'static str) -> egui::Id) {
         ctx.memory_mut(|mem| {
             // Store each filter setting as a separate value for better persistence
-            mem.data.insert_persisted(egui::Id::new("logger_filter_show_info"), self.show_info);
-            mem.data.insert_persisted(egui::Id::new("logger_filter_show_warning"), self.show_warning);
-            mem.data.insert_persisted(egui::Id::new("logger_filter_show_error"), self.show_error);
-            mem.data.insert_persisted(egui::Id::new("logger_filter_show_debug"), self.show_debug);
-            mem.data.insert_persisted(egui::Id::new("logger_filter_show_custom"), self.show_custom);
-            mem.data.insert_persisted(egui::Id::new("logger_filter_show_system"), self.show_system);
-            mem.data.insert_persisted(egui::Id::new("logger_filter_text"), self.text_filter.clone());
+            mem.data.insert_persisted(key("logger_filter_show_info"), self.show_info);
+            mem.data.insert_persisted(key("logger_filter_show_warning"), self.show_warning);
+            mem.data.insert_persisted(key("logger_filter_show_error"), self.show_error);
+            mem.data.insert_persisted(key("logger_filter_show_debug"), self.show_debug);
+            mem.data.insert_persisted(key("logger_filter_show_custom"), self.show_custom);
+            mem.data.insert_persisted(key("logger_filter_show_system"), self.show_system);
+            mem.data.insert_persisted(key("logger_filter_text"), self.text_filter.clone());
+            mem.data.insert_persisted(key("logger_filter_only_custom_type"), self.only_custom_type.clone());
+            mem.data.insert_persisted(key("logger_filter_excluded_patterns"), self.excluded_patterns.clone());
+            mem.data.insert_persisted(key("logger_filter_only_bookmarked"), self.only_bookmarked);
         });
     }
     
-    /// Load filter state from memory
-    pub fn load_from_memory(&mut self, ctx: &egui::Context) {
+    fn load_keys(&mut self, ctx: &egui::Context, key: impl Fn(&'static str) -> egui::Id) {
         // Use temporary variables to store the values from memory
-        let show_info = ctx.memory_mut(|mem| mem.data.get_persisted::<bool>(egui::Id::new("logger_filter_show_info")));
-        let show_warning = ctx.memory_mut(|mem| mem.data.get_persisted::<bool>(egui::Id::new("logger_filter_show_warning")));
-        let show_error = ctx.memory_mut(|mem| mem.data.get_persisted::<bool>(egui::Id::new("logger_filter_show_error")));
-        let show_debug = ctx.memory_mut(|mem| mem.data.get_persisted::<bool>(egui::Id::new("logger_filter_show_debug")));
-        let show_custom = ctx.memory_mut(|mem| mem.data.get_persisted::<bool>(egui::Id::new("logger_filter_show_custom")));
-        let show_system = ctx.memory_mut(|mem| mem.data.get_persisted::<bool>(egui::Id::new("logger_filter_show_system")));
-        let text_filter = ctx.memory_mut(|mem| mem.data.get_persisted::<String>(egui::Id::new("logger_filter_text")));
+        let show_info = ctx.memory_mut(|mem| mem.data.get_persisted::<bool>(key("logger_filter_show_info")));
+        let show_warning = ctx.memory_mut(|mem| mem.data.get_persisted::<bool>(key("logger_filter_show_warning")));
+        let show_error = ctx.memory_mut(|mem| mem.data.get_persisted::<bool>(key("logger_filter_show_error")));
+        let show_debug = ctx.memory_mut(|mem| mem.data.get_persisted::<bool>(key("logger_filter_show_debug")));
+        let show_custom = ctx.memory_mut(|mem| mem.data.get_persisted::<bool>(key("logger_filter_show_custom")));
+        let show_system = ctx.memory_mut(|mem| mem.data.get_persisted::<bool>(key("logger_filter_show_system")));
+        let text_filter = ctx.memory_mut(|mem| mem.data.get_persisted::<String>(key("logger_filter_text")));
+        let only_custom_type = ctx.memory_mut(|mem| mem.data.get_persisted::<Option<String>>(key("logger_filter_only_custom_type")));
+        let excluded_patterns = ctx.memory_mut(|mem| mem.data.get_persisted::<Vec<String>>(key("logger_filter_excluded_patterns")));
+        let only_bookmarked = ctx.memory_mut(|mem| mem.data.get_persisted::<bool>(key("logger_filter_only_bookmarked")));
         
         // Apply the values if they were found
         if let Some(value) = show_info {
@@ -197,6 +320,15 @@ impl LogFilter {
         if let Some(value) = text_filter {
             self.text_filter = value;
         }
+        if let Some(value) = only_custom_type {
+            self.only_custom_type = value;
+        }
+        if let Some(value) = excluded_patterns {
+            self.excluded_patterns = value;
+        }
+        if let Some(value) = only_bookmarked {
+            self.only_bookmarked = value;
+        }
     }
 }
 
@@ -207,25 +339,158 @@ impl LogFilter {
 impl std::fmt::Debug for LogType {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         match self {
-            LogType::Info => write!(f, "INFO"),
-            LogType::Warning => write!(f, "WARNING"),
-            LogType::Error => write!(f, "ERROR"),
-            LogType::Debug => write!(f, "DEBUG"),
-            LogType::Timestamp => write!(f, "TIME"),
-            LogType::System => write!(f, "SYSTEM"),
-            LogType::UserAction => write!(f, "USER"),
-            LogType::Config => write!(f, "CONFIG"),
-            LogType::Status => write!(f, "STATUS"),
-            LogType::Progress => write!(f, "PROGRESS"),
-            LogType::Success => write!(f, "SUCCESS"),
-            LogType::Default => write!(f, "DEFAULT"),
             LogType::Custom(identifier) => write!(f, "CUSTOM:{}", identifier),
+            level => write!(f, "{}", level.level_text().unwrap_or_default()),
+        }
+    }
+}
+
+impl LogType {
+    /// Level text of the type ("INFO", "WARNING", ...), or None for custom types
+    pub fn level_text(&self) -> Option<&'static str> {
+        match self {
+            LogType::Info => Some("INFO"),
+            LogType::Warning => Some("WARNING"),
+            LogType::Error => Some("ERROR"),
+            LogType::Debug => Some("DEBUG"),
+            LogType::Timestamp => Some("TIME"),
+            LogType::System => Some("SYSTEM"),
+            LogType::UserAction => Some("USER"),
+            LogType::Config => Some("CONFIG"),
+            LogType::Status => Some("STATUS"),
+            LogType::Progress => Some("PROGRESS"),
+            LogType::Success => Some("SUCCESS"),
+            LogType::Default => Some("DEFAULT"),
+            LogType::Command => Some("COMMAND"),
+            LogType::Custom(_) => None,
+        }
+    }
+    
+    /// Check if a log entry has this level or custom type
+    pub fn matches(&self, log: &LoggerPayload) -> bool {
+        match self {
+            LogType::Custom(identifier) => log.level().strip_prefix("CUSTOM:") == Some(identifier.as_str()),
+            level => level.level_text() == Some(log.level()),
         }
     }
 }
 
+/// DetailPane
+///
+/// Where the detail inspector for the selected entry is shown.
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub enum DetailPane {
+    /// No detail pane
+    Hidden,
+    /// Resizable pane to the right of the log table
+    #[default]
+    Right,
+    /// Resizable pane below the log table
+    Bottom,
+}
+
+/// Deduplication
+///
+/// Controls whether identical log entries (same level and message) are
+/// merged into a single row with a repeat counter.
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub enum Deduplication {
+    /// Every entry gets its own row
+    #[default]
+    Off,
+    /// Merge an entry into the previous one if they are identical
+    Consecutive,
+    /// Merge an entry into any identical entry logged within the window
+    Window(std::time::Duration),
+}
+
+/// RowOrder
+///
+/// Order in which the log table shows its rows.
+#[derive(Clone, Copy, Debug, Default, PartialEq, serde::Serialize, serde::Deserialize)]
+pub enum RowOrder {
+    /// Newest entries at the top
+    #[default]
+    NewestFirst,
+    /// Oldest entries at the top, newest at the bottom (like a terminal)
+    OldestFirst,
+}
+
+/// SplitView
+///
+/// Shows a second table of the same buffer with its own filter, so two
+/// streams can be compared. The panes scroll in sync by time.
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub enum SplitView {
+    /// A single table
+    #[default]
+    Off,
+    /// Two tables next to each other
+    SideBySide,
+    /// Two tables above each other
+    Stacked,
+}
+
+/// UnreadCounts
+///
+/// Entries added since the logger panel was last shown, per severity.
+/// Custom types count as info. A repeat merged into an existing row by
+/// deduplication counts like a new entry, and entries keep counting after
+/// they have been evicted from the buffer.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct UnreadCounts {
+    /// Unread errors
+    pub errors: usize,
+    /// Unread warnings
+    pub warnings: usize,
+    /// Unread info and custom type entries
+    pub info: usize,
+    /// Unread debug and message-only entries
+    pub debug: usize,
+}
+
+impl UnreadCounts {
+    /// Number of unread entries of all severities
+    pub fn total(&self) -> usize {
+        self.errors + self.warnings + self.info + self.debug
+    }
+    
+    // Count an entry of the given level
+    fn add(&mut self, level: &str) {
+        match severity(level) {
+            3 => self.errors += 1,
+            2 => self.warnings += 1,
+            1 => self.info += 1,
+            _ => self.debug += 1,
+        }
+    }
+}
 
-// This constant is now directly used in ReactiveEventLoggerState::new()
+/// RowAction
+///
+/// Something the user did with a row of the log table during a frame.
+/// Actions are collected while the table is drawn and applied afterwards,
+/// once the displayed row order is known.
+enum RowAction {
+    /// Row clicked with the given modifiers (selection)
+    Click(u64, egui::Modifiers),
+    /// Copy the message of a row
+    CopyMessage(u64),
+    /// Copy a row as JSON
+    CopyJson(u64),
+    /// Copy all selected rows in the export text format
+    CopySelected,
+    /// Show only logs of the same type as this row
+    FilterToType(u64),
+    /// Hide all messages similar to this row
+    ExcludeLike(u64),
+    /// Add or remove the bookmark of a row
+    ToggleBookmark(u64),
+    /// Collapse or expand an operation group
+    ToggleGroup(u64),
+    /// Show only the entries of an operation group
+    FilterToGroup(u64),
+}
 
 /// ReactiveEventLoggerState
 /// 
@@ -236,8 +501,8 @@ impl std::fmt::Debug for LogType {
 /// when the state changes.
 /// 
 /// It maintains a circular buffer of log messages with a maximum capacity
-/// of 1000 entries. When the buffer is full, the oldest entry is removed
-/// before adding a new one.
+/// of `LoggerConfig::max_logs` entries (1000 by default). When the buffer
+/// is full, the oldest entry is removed before adding a new one.
 #[derive(Default, Clone)]
 pub struct ReactiveEventLoggerState {
     pub show_timestamps : bool,               // show/hide timestamps
@@ -246,146 +511,989 @@ pub struct ReactiveEventLoggerState {
     pub logs            : Vec<LoggerPayload>, // store log messages in a circular buffer
     pub max_logs        : usize,              // maximum number of log entries to store
     pub filter          : LogFilter,          // filtering options for log messages
+    pub strip_ansi      : bool,               // strip ANSI escape codes instead of rendering them
+    pub next_sequence   : u64,                // sequence number for the next stored log entry
+    pub selected        : BTreeSet<u64>,      // sequence numbers of the selected rows
+    pub selection_anchor: Option<u64>,        // row a shift-click range selection starts from
+    pub detail_pane     : DetailPane,         // placement of the selected entry inspector
+    pub pretty_json     : bool,               // render JSON messages as colored previews and trees
+    pub deduplication   : Deduplication,      // merging of identical entries into one row
+    pub rate_limiter    : RateLimiter,        // token-bucket limits dropping log floods
+    pub retention       : Vec<RetentionRule>, // per-level count and age limits
+    pub memory_budget   : Option<MemoryBudget>, // optional limit of the buffer's heap size
+    pub search          : LogSearch,          // search bar query, options and current hit
+    pub filter_presets  : FilterPresets,      // named filters selectable next to the Filters button
+    pub active_preset   : Option<String>,     // name of the last applied filter preset
+    pub row_order       : RowOrder,           // newest-first or chronological table rows
+    pub follow          : bool,               // keep the newest entries in view
+    pub paused_at       : Option<u64>,        // first sequence number received while paused
+    pub frozen_at       : Option<u64>,        // first sequence number hidden by a frozen view
+    pub open_groups     : HashMap<ThreadId, Vec<u64>>, // per thread, header sequence numbers of the open operation groups
+    pub collapsed_groups: HashSet<u64>,       // header sequence numbers of collapsed groups
+    pub channels        : Vec<String>,        // named channels, in order of appearance
+    pub active_channel  : Option<String>,     // channel tab shown, or None for all channels
+    pub channels_unread : HashMap<String, UnreadCounts>, // per channel, entries added since it was viewed
+    pub split_view      : SplitView,          // second table pane for comparing two streams
+    pub split_filter    : LogFilter,          // filter of the second pane of a split view
+    pub popped_out      : bool,               // shown in its own viewport window instead of the panel
+    pub config          : LoggerConfig,       // toolbar, column and row settings of the panel
+    pub toolbar_actions : Vec<ToolbarAction>, // application buttons and menus in the toolbar
+    pub hooks           : LogHooks,           // callbacks on ingested entries
+    pub alerts          : Alerts,             // alert rules and the alerts they raised
+    pub unread          : UnreadCounts,       // entries added since the panel was last shown
+    pub commands        : CommandRegistry,    // commands of the command line
+    pub command_history : Vec<String>,        // commands run from the command line, oldest first
+    memory_used         : usize,              // approximate heap size of the stored entries
+    edits               : u64,                // in-place changes of stored entries, for cached results
 }
 
 impl ReactiveEventLoggerState {
     pub fn new() -> Self {
-        // Maximum number of logs to keep is hardcoded to 1000
-        const MAX_LOGS: usize = 1000;
+        let config = LoggerConfig::default();
         
         Self {
-            show_timestamps : true,
-            show_log_level  : true,
-            show_messages   : true,
-            logs            : Vec::with_capacity(MAX_LOGS),
-            max_logs        : MAX_LOGS,
+            show_timestamps : config.columns.timestamps,
+            show_log_level  : config.columns.log_level,
+            show_messages   : config.columns.messages,
+            logs            : Vec::with_capacity(config.max_logs),
+            max_logs        : config.max_logs,
             filter          : LogFilter::default(),
+            strip_ansi      : false,
+            next_sequence   : 1,
+            selected        : BTreeSet::new(),
+            selection_anchor: None,
+            detail_pane     : DetailPane::default(),
+            pretty_json     : true,
+            deduplication   : Deduplication::default(),
+            rate_limiter    : RateLimiter::default(),
+            retention       : Vec::new(),
+            memory_budget   : None,
+            search          : LogSearch::default(),
+            filter_presets  : FilterPresets::default(),
+            active_preset   : None,
+            row_order       : config.order,
+            follow          : true,
+            paused_at       : None,
+            frozen_at       : None,
+            open_groups     : HashMap::new(),
+            collapsed_groups: HashSet::new(),
+            channels        : Vec::new(),
+            active_channel  : None,
+            channels_unread : HashMap::new(),
+            split_view      : SplitView::default(),
+            split_filter    : LogFilter::default(),
+            popped_out      : false,
+            config,
+            toolbar_actions : Vec::new(),
+            hooks           : LogHooks::default(),
+            alerts          : Alerts::default(),
+            unread          : UnreadCounts::default(),
+            commands        : CommandRegistry::default(),
+            command_history : Vec::new(),
+            memory_used     : 0,
+            edits           : 0,
         }
     }
 
     /// Add a log entry to the circular buffer
-    /// If the buffer is full, the oldest entry is removed
+    /// If the buffer is full, the oldest entry is removed.
+    /// Entries exceeding a rate limit are dropped and later summarized.
     pub fn add_log(&mut self, log: LoggerPayload) {
-        // If we've reached capacity, remove the oldest entry (front of the vector)
-        if self.logs.len() >= self.max_logs {
-            self.logs.remove(0); // Remove the first (oldest) element
+        let now = std::time::Instant::now();
+        let admitted = self.rate_limiter.admit(&log, now);
+        self.push_rate_limit_summaries(now);
+        
+        if admitted {
+            self.insert_log(log);
+        }
+    }
+    
+    /// Add summary entries for rate limits that have been dropping entries
+    /// for longer than the summary interval
+    pub fn flush_rate_limit_summaries(&mut self) {
+        self.push_rate_limit_summaries(std::time::Instant::now());
+    }
+    
+    fn push_rate_limit_summaries(&mut self, now: std::time::Instant) {
+        for summary in self.rate_limiter.take_summaries(now) {
+            let mut payload = LoggerPayload::new();
+            payload.warning().message(summary).update();
+            self.insert_log(payload);
+        }
+    }
+    
+    /// Store a log entry, bypassing the rate limits
+    fn insert_log(&mut self, mut log: LoggerPayload) {
+        // Merge identical entries into an existing row if deduplication is on
+        if let Some(index) = self.find_duplicate(&log) {
+            let existing = &mut self.logs[index];
+            existing.repeat_count += log.repeat_count;
+            existing.last_seen_at = log.last_seen_at;
+            self.hooks.dispatch(existing);
+            self.alerts.check(existing, std::time::Instant::now());
+            self.count_unread(index);
+            return;
         }
         
+        // Truncate oversized messages before they are stored
+        if let Some(budget) = &self.memory_budget {
+            budget.truncate_message(&mut log);
+        }
+        
+        // Register the channel of the entry for the tab strip
+        if let Some(channel) = &log.channel
+            && !self.channels.contains(channel) {
+            self.channels.push(channel.clone());
+        }
+        
+        // Nest the entry in the operation groups opened on this thread
+        log.groups = self.open_groups.get(&thread::current().id()).cloned().unwrap_or_default();
+        
+        // Stamp the entry with its sequence number
+        log.sequence = self.next_sequence;
+        self.next_sequence += 1;
+        
         // Add the new log entry at the end
+        self.memory_used += log.heap_size();
         self.logs.push(log);
+        self.count_unread(self.logs.len() - 1);
+        
+        // Tell the hooks and alert rules before retention can remove the entry again
+        if let Some(log) = self.logs.last() {
+            self.hooks.dispatch(log);
+            self.alerts.check(log, std::time::Instant::now());
+        }
+        
+        self.enforce_limits();
     }
     
-    /// Clear all log entries
-    pub fn clear_logs(&mut self) {
-        self.logs.clear();
+    /// Count a new or repeated entry as unread, in total and in its channel
+    fn count_unread(&mut self, index: usize) {
+        let log = &self.logs[index];
+        self.unread.add(log.level());
+        if let Some(channel) = &log.channel {
+            match self.channels_unread.get_mut(channel) {
+                Some(counts) => counts.add(log.level()),
+                None => {
+                    let mut counts = UnreadCounts::default();
+                    counts.add(log.level());
+                    self.channels_unread.insert(channel.clone(), counts);
+                }
+            }
+        }
     }
     
-    /// Get the number of log entries
-    pub fn log_count(&self) -> usize {
-        self.logs.len()
+    /// Evict entries exceeding the buffer size, the retention rules or the
+    /// memory budget. While the view is frozen, the buffer may grow to
+    /// `FROZEN_GROWTH` times its limits, so entries arriving meanwhile are
+    /// kept; the retention rules only apply to those entries.
+    fn enforce_limits(&mut self) {
+        let growth = if self.frozen_at.is_some() { FROZEN_GROWTH } else { 1 };
+        
+        // Enforce the per-level retention rules first, so the buffer size
+        // only evicts entries the rules would keep if they don't suffice
+        if !self.retention.is_empty() {
+            let mut unfrozen = self.logs.split_off(self.first_unfrozen());
+            let expired = apply_retention(&self.retention, &mut unfrozen, chrono::Local::now());
+            self.logs.append(&mut unfrozen);
+            Self::forget_logs(&mut self.memory_used, expired);
+        }
+        
+        // If we've reached capacity, remove the oldest entries
+        let excess = self.logs.len().saturating_sub(self.max_logs.saturating_mul(growth));
+        Self::forget_logs(&mut self.memory_used, self.logs.drain(..excess));
+        
+        // Evict the oldest entries while the memory budget is exceeded
+        if let Some(budget) = &self.memory_budget {
+            let max_bytes = budget.max_bytes.saturating_mul(growth);
+            let mut used = self.memory_used;
+            let mut evict = 0;
+            while used > max_bytes && evict + 1 < self.logs.len() {
+                used -= self.logs[evict].heap_size();
+                evict += 1;
+            }
+            Self::forget_logs(&mut self.memory_used, self.logs.drain(..evict));
+        }
     }
     
-    /// Set the maximum number of log entries
-    #[allow(dead_code)]
-    pub fn set_max_logs(&mut self, max_logs: usize) {
-        self.max_logs = max_logs;
-        
-        // If the current number of logs exceeds the new maximum,
-        // remove the oldest entries until we're at the new maximum
-        while self.logs.len() > self.max_logs {
-            self.logs.remove(0);
+    /// Account for entries leaving the buffer: subtract their size from the
+    /// memory usage and delete the archive files of truncated messages
+    fn forget_logs(memory_used: &mut usize, logs: impl IntoIterator<Item = LoggerPayload>) {
+        for log in logs {
+            *memory_used = memory_used.saturating_sub(log.heap_size());
+            if let Some(path) = &log.archived {
+                remove_archive(path);
+            }
         }
     }
-}
-
-/// ReactiveEventLogger
-/// 
-/// This struct is the main component for logging events in the application.
-/// It processes LoggerPayload objects which contain:
-///
-/// - Timestamp - When the log was created
-/// - LogLevel - The severity level (info, warn, debug, error)
-/// - Message - The actual content
-///
-/// The logger provides a terminal-like interface that displays logs in a table format.
-/// Users can toggle which columns to display (timestamps, log levels, messages).
-///
-/// The ReactiveEventLogger uses a shared state (ReactiveEventLoggerState) to maintain 
-/// the log entries and display settings, making it reactive to UI changes.
-pub struct ReactiveEventLogger<'a> {
-    state: &'a Dynamic<ReactiveEventLoggerState>,  // shared state of the logger panel
-    colors: Option<&'a Dynamic<LogColors>>,        // optional colors for the log messages
-}
-
-impl<'a> ReactiveEventLogger<'a> {
-    /// Create a new ReactiveEventLogger with a shared state
-    #[allow(dead_code)]
-    pub fn new(state: &'a Dynamic<ReactiveEventLoggerState>) -> Self {
-        Self {
-            state,
-            colors: None,
+    
+    /// Modify a stored entry in place, keeping the memory usage in step
+    fn update_log(&mut self, sequence: u64, update: impl FnOnce(&mut LoggerPayload)) {
+        if let Ok(index) = self.logs.binary_search_by_key(&sequence, |log| log.sequence) {
+            let log = &mut self.logs[index];
+            let before = log.heap_size();
+            update(log);
+            self.memory_used = (self.memory_used + log.heap_size()).saturating_sub(before);
+            self.edits += 1;
         }
     }
     
-    /// Save colors to gerber_viewer specific config directory
-    fn save_colors_for_gerber_viewer(colors: &LogColors) {
-        use std::path::PathBuf;
-        use std::fs;
+    /// Index of the first entry received since the view was frozen, or 0.
+    /// Older entries are not merged into while frozen.
+    fn first_unfrozen(&self) -> usize {
+        self.frozen_at
+            .map_or(0, |frozen_at| self.logs.partition_point(|log| log.sequence < frozen_at))
+    }
+    
+    /// Find the index of an entry the given log should be merged into
+    fn find_duplicate(&self, log: &LoggerPayload) -> Option<usize> {
+        // Live progress entries, group headers and commands are never merged
+        if log.progress.is_some() || log.group.is_some() || LogType::Command.matches(log) {
+            return None;
+        }
         
-        let colors = colors.clone();
-        std::thread::spawn(move || {
-            // Get config directory path for gerber_viewer
-            let config_dir = dirs::config_dir()
-                .unwrap_or_else(|| PathBuf::from("."))
-                .join("gerber_viewer");
-            
-            // Create config directory if it doesn't exist
-            if let Err(e) = fs::create_dir_all(&config_dir) {
-                eprintln!("Failed to create config directory: {}", e);
-                return;
+        let first = self.first_unfrozen();
+        match self.deduplication {
+            Deduplication::Off => None,
+            Deduplication::Consecutive => {
+                let index = self.logs.len().checked_sub(1).filter(|index| *index >= first)?;
+                self.logs[index].is_duplicate_of(log).then_some(index)
             }
-            
-            // Create config file path
-            let config_path = config_dir.join("log_colors.json");
-            
-            // Serialize colors to JSON
-            match serde_json::to_string_pretty(&colors) {
-                Ok(json) => {
-                    // Write JSON to file
-                    if let Err(e) = fs::write(&config_path, json) {
-                        eprintln!("Failed to write colors to {}: {}", config_path.display(), e);
-                    } else {
-                        println!("Successfully saved colors to {}", config_path.display());
-                    }
-                },
-                Err(e) => eprintln!("Failed to serialize colors: {}", e),
+            Deduplication::Window(window) => {
+                // An entry qualifies if its latest occurrence is within the window.
+                // Merged entries keep their position, so last_seen_at is not sorted
+                // and the whole buffer has to be searched.
+                let oldest = chrono::Duration::from_std(window)
+                    .ok()
+                    .and_then(|window| log.created_at.checked_sub_signed(window));
+                self.logs[first..]
+                    .iter()
+                    .rposition(|existing| {
+                        oldest.is_none_or(|oldest| existing.last_seen_at >= oldest)
+                            && existing.is_duplicate_of(log)
+                    })
+                    .map(|index| first + index)
+            }
+        }
+    }
+    
+    /// Clear all log entries
+    pub fn clear_logs(&mut self) {
+        Self::forget_logs(&mut self.memory_used, self.logs.drain(..));
+        self.collapsed_groups.clear();
+        self.clear_selection();
+        self.unread = UnreadCounts::default();
+        self.channels_unread.clear();
+    }
+    
+    /// Replace the filter with a saved preset
+    pub fn apply_filter_preset(&mut self, name: &str) -> bool {
+        match self.filter_presets.get(name) {
+            Some(preset) => {
+                self.filter = preset.filter.clone();
+                self.active_preset = Some(name.to_string());
+                true
+            }
+            None => false,
+        }
+    }
+    
+    /// Save the current filter as a preset and make it the active one
+    pub fn save_filter_preset(&mut self, name: &str) {
+        self.filter_presets.save_preset(name, self.filter.clone());
+        self.active_preset = Some(name.to_string());
+    }
+    
+    /// Rename a filter preset, keeping it active if it was
+    pub fn rename_filter_preset(&mut self, name: &str, new_name: &str) -> bool {
+        if !self.filter_presets.rename(name, new_name) {
+            return false;
+        }
+        if self.active_preset.as_deref() == Some(name) {
+            self.active_preset = Some(new_name.trim().to_string());
+        }
+        true
+    }
+    
+    /// Delete a filter preset
+    pub fn delete_filter_preset(&mut self, name: &str) {
+        self.filter_presets.remove(name);
+        if self.active_preset.as_deref() == Some(name) {
+            self.active_preset = None;
+        }
+    }
+    
+    /// Text describing the active preset, marked if the filter was changed since
+    pub fn active_preset_label(&self) -> Option<String> {
+        let name = self.active_preset.as_ref()?;
+        match self.filter_presets.get(name) {
+            Some(preset) if preset.filter == self.filter => Some(name.clone()),
+            _ => Some(format!("{} (modified)", name)),
+        }
+    }
+    
+    /// Find a stored log entry by its sequence number
+    pub fn find_log(&self, sequence: u64) -> Option<&LoggerPayload> {
+        // Sequence numbers are increasing, so the buffer is sorted by them
+        self.logs
+            .binary_search_by_key(&sequence, |log| log.sequence)
+            .ok()
+            .map(|index| &self.logs[index])
+    }
+    
+    /// Find a stored log entry by its sequence number for modification
+    pub fn find_log_mut(&mut self, sequence: u64) -> Option<&mut LoggerPayload> {
+        self.logs
+            .binary_search_by_key(&sequence, |log| log.sequence)
+            .ok()
+            .map(|index| &mut self.logs[index])
+    }
+    
+    /// Store a live progress entry for an operation of `total` units and
+    /// return its sequence number. Progress entries bypass the rate limits.
+    pub fn start_progress(&mut self, mut log: LoggerPayload, total: u64) -> u64 {
+        log.progress = Some(Progress::new(&log.log_message.content.value, total));
+        log.info_slot_level("PROGRESS");
+        self.insert_log(log);
+        self.next_sequence - 1
+    }
+    
+    /// Update the units of work done of a live progress entry
+    pub fn set_progress(&mut self, sequence: u64, current: u64) {
+        if let Some(progress) = self.find_log_mut(sequence).and_then(|log| log.progress.as_mut()) {
+            progress.current = current;
+        }
+    }
+    
+    /// Turn a live progress entry into a normal info entry, or an error
+    /// entry if the operation failed. The hooks are called again with the
+    /// finished entry.
+    pub fn finish_progress(&mut self, sequence: u64, error: Option<&str>) {
+        let mut finished = false;
+        self.update_log(sequence, |log| {
+            if let Some(progress) = log.progress.take() {
+                let now = std::time::Instant::now();
+                match error {
+                    None => log.info(),
+                    Some(_) => log.error(),
+                };
+                log.message(progress.finished_message(error, now));
+                log.last_seen_at = chrono::Local::now();
+                finished = true;
             }
         });
+        if finished && let Ok(index) = self.logs.binary_search_by_key(&sequence, |log| log.sequence) {
+            self.hooks.dispatch(&self.logs[index]);
+        }
     }
     
-    /// Show the filter modal dialog
-    fn show_filter_modal(&self, ui: &mut egui::Ui) {
-        // Check if filter modal should be shown
-        let show_filter_modal = ui.ctx().memory(|mem| {
-            mem.data.get_temp::<bool>(egui::Id::new("show_logger_filter_modal")).unwrap_or(false)
+    /// Check if any stored entry is a live progress entry
+    pub fn has_active_progress(&self) -> bool {
+        self.logs.iter().any(|log| log.progress.is_some())
+    }
+    
+    /// Store the header entry of an operation group and open the group, so
+    /// subsequent entries from the calling thread are nested under it.
+    /// Returns its sequence number.
+    pub fn begin_group(&mut self, mut log: LoggerPayload) -> u64 {
+        log.group = Some(LogGroup::new());
+        self.insert_log(log);
+        let sequence = self.next_sequence - 1;
+        self.open_groups.entry(thread::current().id()).or_default().push(sequence);
+        sequence
+    }
+    
+    /// Close an operation group, recording its duration and worst severity.
+    /// A group containing warnings or errors takes on that level.
+    pub fn end_group(&mut self, sequence: u64) {
+        for open in self.open_groups.values_mut() {
+            open.retain(|open| *open != sequence);
+        }
+        self.open_groups.retain(|_, open| !open.is_empty());
+        
+        let worst_level = self.logs
+            .iter()
+            .filter(|log| log.groups.contains(&sequence))
+            .map(|log| log.level())
+            .max_by_key(|level| severity(level))
+            .map(str::to_string);
+        
+        self.update_log(sequence, |header| {
+            if let Some(group) = header.group.as_mut() {
+                group.duration = Some(group.started_at.elapsed());
+                match worst_level.as_deref() {
+                    Some("ERROR") => { header.error(); }
+                    Some("WARNING") => { header.warning(); }
+                    _ => {}
+                }
+                if let Some(group) = header.group.as_mut() {
+                    group.worst_level = worst_level;
+                }
+            }
         });
+    }
+    
+    /// Collapse or expand an operation group
+    pub fn toggle_group(&mut self, sequence: u64) {
+        if !self.collapsed_groups.remove(&sequence) {
+            self.collapsed_groups.insert(sequence);
+        }
+    }
+    
+    /// Add a channel tab before the first entry of the channel arrives
+    pub fn add_channel(&mut self, name: &str) {
+        if !self.channels.iter().any(|channel| channel == name) {
+            self.channels.push(name.to_string());
+        }
+    }
+    
+    /// Show a single channel, or all channels with `None`
+    pub fn set_active_channel(&mut self, channel: Option<&str>) {
+        self.active_channel = channel.map(str::to_string);
+        if let Some(channel) = channel {
+            self.mark_channel_read(channel);
+        }
+    }
+    
+    /// Mark all entries of a channel as read
+    pub fn mark_channel_read(&mut self, channel: &str) {
+        self.channels_unread.remove(channel);
+    }
+    
+    /// Number of unread entries and unread errors of a channel
+    pub fn channel_unread(&self, channel: &str) -> (usize, usize) {
+        self.channels_unread
+            .get(channel)
+            .map_or((0, 0), |counts| (counts.total(), counts.errors))
+    }
+    
+    /// Number of entries per severity added since the panel was last shown
+    pub fn unread_counts(&self) -> UnreadCounts {
+        self.unread
+    }
+    
+    /// Mark all entries as read, clearing the unread counts
+    pub fn mark_all_read(&mut self) {
+        self.unread = UnreadCounts::default();
+    }
+    
+    /// Run a command line.
+    ///
+    /// The input is added to the history and echoed as a COMMAND entry,
+    /// followed by the output of the command. If the command is unknown or
+    /// fails, the error is a COMMAND entry too, starting with
+    /// `COMMAND_ERROR_MARKER`, so a typo raises no error badges or alerts.
+    pub fn run_command(&mut self, input: &str) {
+        let input = input.trim();
+        let mut words = input.split_whitespace();
+        let Some(name) = words.next() else {
+            return;
+        };
+        let args: Vec<&str> = words.collect();
         
-        if show_filter_modal {
-            // Get reference to the state
-            let state_ref = ReactiveWidgetRef::from_dynamic(self.state);
-            
-            if let Some(state_arc) = state_ref.weak_ref.upgrade() {
-                // Get a mutable reference to the state
-                if let Ok(mut state) = state_arc.lock() {
-                    // Create a local copy of the filter for editing
-                    let mut filter = state.filter.clone();
-                    
-                    // Load saved filter settings (only once when opening the modal)
-                    filter.load_from_memory(ui.ctx());
-                    
-                    // Create modal window
-                    let modal_id = egui::Id::new("logger_filter_modal");
-                    egui::Window::new("Log Filters")
+        // Remember the command, without repeating the last one
+        if self.command_history.last().map(String::as_str) != Some(input) {
+            self.command_history.push(input.to_string());
+            let excess = self.command_history.len().saturating_sub(MAX_HISTORY);
+            self.command_history.drain(..excess);
+        }
+        
+        self.insert_command_entry(format!("> {}", input));
+        let result = match self.commands.get(name).map(|command| command.handler.clone()) {
+            Some(handler) => handler(&args, self),
+            None => Err(format!("Unknown command: {} (type help for the list)", name)),
+        };
+        match result {
+            Ok(output) if output.is_empty() => {}
+            Ok(output) => self.insert_command_entry(output),
+            Err(error) => self.insert_command_entry(format!("{}{}", COMMAND_ERROR_MARKER, error)),
+        }
+    }
+    
+    // Add the echo or output of a command, bypassing the rate limits
+    fn insert_command_entry(&mut self, message: String) {
+        let mut log = LoggerPayload::new();
+        log.info_slot_level("COMMAND").message(message).update();
+        self.insert_log(log);
+    }
+    
+    /// Add or remove the bookmark of an entry
+    pub fn toggle_bookmark(&mut self, sequence: u64) {
+        self.update_log(sequence, |log| log.bookmarked = !log.bookmarked);
+    }
+    
+    /// Attach a note to an entry; an empty note removes it
+    pub fn set_note(&mut self, sequence: u64, note: &str) {
+        self.update_log(sequence, |log| log.note = (!note.trim().is_empty()).then(|| note.to_string()));
+    }
+    
+    /// The bookmarked entries, oldest first
+    pub fn bookmarked_logs(&self) -> Vec<&LoggerPayload> {
+        self.logs.iter().filter(|log| log.bookmarked).collect()
+    }
+    
+    /// All entries, including bookmarks and notes, as a JSON document
+    pub fn to_json(&self) -> serde_json::Value {
+        serde_json::json!({
+            "exported": chrono::Local::now().to_rfc3339(),
+            "entries": self.logs.iter().map(LoggerPayload::to_json).collect::<Vec<_>>(),
+        })
+    }
+    
+    /// Save the entries, bookmarks and notes to a session file
+    pub fn save_session(&self, path: &std::path::Path) -> std::io::Result<()> {
+        let json = serde_json::to_string_pretty(&self.to_json()).map_err(std::io::Error::other)?;
+        std::fs::write(path, json)
+    }
+    
+    /// Replace the entries with those of a session file (or JSON export)
+    pub fn load_session(&mut self, path: &std::path::Path) -> std::io::Result<()> {
+        let content = std::fs::read_to_string(path)?;
+        let value: serde_json::Value = serde_json::from_str(&content).map_err(std::io::Error::other)?;
+        let entries = value.get("entries")
+            .and_then(|entries| entries.as_array())
+            .ok_or_else(|| std::io::Error::other("Not a logger session file"))?;
+        
+        let mut logs: Vec<LoggerPayload> = entries.iter().filter_map(LoggerPayload::from_json).collect();
+        logs.sort_by_key(|log| log.sequence);
+        
+        // Keep the newest entries that fit, renumbering entries without a sequence number
+        let excess = logs.len().saturating_sub(self.max_logs);
+        Self::forget_logs(&mut self.memory_used, std::mem::replace(&mut self.logs, logs.split_off(excess)));
+        self.memory_used = self.logs.iter().map(LoggerPayload::heap_size).sum();
+        self.next_sequence = 1;
+        for log in &mut self.logs {
+            if log.sequence < self.next_sequence {
+                log.sequence = self.next_sequence;
+            }
+            self.next_sequence = log.sequence + 1;
+        }
+        self.edits += 1;
+        self.clear_selection();
+        self.frozen_at = None;
+        self.open_groups.clear();
+        self.collapsed_groups.clear();
+        self.filter.only_group = None;
+        for channel in self.logs.iter().filter_map(|log| log.channel.clone()) {
+            if !self.channels.contains(&channel) {
+                self.channels.push(channel);
+            }
+        }
+        self.resume_follow();
+        Ok(())
+    }
+    
+    /// Update the row selection in response to a click.
+    ///
+    /// `order` lists the sequence numbers of the rows in display order and
+    /// is used to resolve shift-click ranges. A command (ctrl) click toggles
+    /// a single row, a plain click selects only the clicked row.
+    pub fn select_log(&mut self, sequence: u64, modifiers: egui::Modifiers, order: &[u64]) {
+        let anchor = self.selection_anchor
+            .and_then(|anchor| order.iter().position(|s| *s == anchor));
+        let clicked = order.iter().position(|s| *s == sequence);
+        
+        if let (true, Some(anchor), Some(clicked)) = (modifiers.shift, anchor, clicked) {
+            // Range selection keeps the anchor so the range can be adjusted
+            if !modifiers.command {
+                self.selected.clear();
+            }
+            let (start, end) = (anchor.min(clicked), anchor.max(clicked));
+            self.selected.extend(order[start..=end].iter().copied());
+        } else if modifiers.command {
+            if !self.selected.remove(&sequence) {
+                self.selected.insert(sequence);
+            }
+            self.selection_anchor = Some(sequence);
+        } else {
+            self.selected.clear();
+            self.selected.insert(sequence);
+            self.selection_anchor = Some(sequence);
+        }
+    }
+    
+    /// Get the entry shown in the detail pane: the last clicked row if it
+    /// is selected, otherwise the newest selected row
+    pub fn focused_log(&self) -> Option<&LoggerPayload> {
+        self.selection_anchor
+            .filter(|anchor| self.selected.contains(anchor))
+            .or_else(|| self.selected.last().copied())
+            .and_then(|sequence| self.find_log(sequence))
+    }
+    
+    /// Clear the row selection
+    pub fn clear_selection(&mut self) {
+        self.selected.clear();
+        self.selection_anchor = None;
+    }
+    
+    /// Get the selected rows (oldest first) in the export text format
+    pub fn selected_logs_text(&self) -> String {
+        self.logs
+            .iter()
+            .filter(|log| self.selected.contains(&log.sequence))
+            .map(format_log_line)
+            .collect::<Vec<_>>()
+            .join("\n")
+    }
+    
+    /// Get the number of log entries
+    pub fn log_count(&self) -> usize {
+        self.logs.len()
+    }
+    
+    /// The entries passing the filter, in the order of the table rows.
+    /// While the view is frozen, entries received since are left out.
+    /// With channels, only the active channel is shown, or all channels
+    /// merged by timestamp.
+    pub fn displayed_logs(&self) -> Vec<&LoggerPayload> {
+        self.displayed_logs_with(&self.filter)
+    }
+    
+    /// The entries passing another filter (e.g. that of the second pane of
+    /// a split view), in the order of the table rows
+    pub fn displayed_logs_with(&self, filter: &LogFilter) -> Vec<&LoggerPayload> {
+        let mut displayed: Vec<&LoggerPayload> = self.logs
+            .iter()
+            .filter(|log| self.frozen_at.is_none_or(|frozen_at| log.sequence < frozen_at))
+            .filter(|log| self.active_channel.is_none() || log.channel == self.active_channel)
+            .filter(|log| !log.groups.iter().any(|group| self.collapsed_groups.contains(group)))
+            .filter(|log| filter.should_display(log))
+            .collect();
+        
+        // Entries of different channels may be created before they are stored.
+        // They rarely are, so check first instead of sorting every frame.
+        if self.active_channel.is_none()
+            && !self.channels.is_empty()
+            && !displayed.is_sorted_by_key(|log| log.created_at) {
+            displayed.sort_by_key(|log| log.created_at);
+        }
+        
+        if self.row_order == RowOrder::NewestFirst {
+            displayed.reverse();
+        }
+        displayed
+    }
+    
+    /// Sequence numbers of the displayed entries matching a search,
+    /// in display order. Messages are searched as the table shows them,
+    /// e.g. JSON as its compact preview.
+    pub fn search_hits(&self, matcher: &SearchMatcher) -> Vec<u64> {
+        self.displayed_logs()
+            .into_iter()
+            .filter(|log| matcher.is_match(&rendered_message(log, self.pretty_json)))
+            .map(|log| log.sequence)
+            .collect()
+    }
+    
+    // What the search hits depend on besides the matcher
+    fn search_key(&self, search: &LogSearch) -> SearchKey {
+        SearchKey {
+            query: search.query.clone(),
+            regex: search.regex,
+            case_sensitive: search.case_sensitive,
+            next_sequence: self.next_sequence,
+            log_count: self.logs.len(),
+            edits: self.edits,
+            filter: self.filter.clone(),
+            active_channel: self.active_channel.clone(),
+            frozen_at: self.frozen_at,
+            collapsed_groups: self.collapsed_groups.clone(),
+            row_order: self.row_order,
+            pretty_json: self.pretty_json,
+        }
+    }
+    
+    /// Stop following new entries, e.g. because the user scrolled away
+    pub fn pause_follow(&mut self) {
+        if self.follow {
+            self.follow = false;
+            self.paused_at = Some(self.next_sequence);
+        }
+    }
+    
+    /// Follow new entries again
+    pub fn resume_follow(&mut self) {
+        self.follow = true;
+        self.paused_at = None;
+    }
+    
+    /// Freeze the view: entries received from now on are stored but not
+    /// displayed until the view is unfrozen. The displayed entries are kept
+    /// as they are: they are neither evicted nor merged with new duplicates.
+    pub fn freeze(&mut self) {
+        if self.frozen_at.is_none() {
+            self.frozen_at = Some(self.next_sequence);
+        }
+    }
+    
+    /// Show all entries again, evicting those the limits no longer allow
+    pub fn unfreeze(&mut self) {
+        self.frozen_at = None;
+        self.enforce_limits();
+    }
+    
+    /// Number of entries received since the view was frozen
+    pub fn received_since_freeze(&self) -> u64 {
+        self.frozen_at.map_or(0, |frozen_at| self.next_sequence - frozen_at)
+    }
+    
+    /// Number of displayed entries received since following was paused
+    pub fn new_entries_since_pause(&self) -> usize {
+        match self.paused_at {
+            Some(paused_at) => self.logs
+                .iter()
+                .filter(|log| log.sequence >= paused_at && self.filter.should_display(log))
+                .count(),
+            None => 0,
+        }
+    }
+    
+    /// Approximate heap size of the stored entries in bytes, kept up to
+    /// date as entries are stored, updated and evicted
+    pub fn memory_usage(&self) -> usize {
+        self.memory_used
+    }
+    
+    /// Count the stored entries per level, most severe standard levels
+    /// first, followed by the other levels and custom types by name
+    pub fn level_counts(&self) -> Vec<(String, usize)> {
+        const STANDARD_LEVELS: [&str; 4] = ["ERROR", "WARNING", "INFO", "DEBUG"];
+        
+        let mut counts = std::collections::BTreeMap::<&str, usize>::new();
+        for log in &self.logs {
+            if !log.level().is_empty() {
+                *counts.entry(log.level()).or_default() += 1;
+            }
+        }
+        
+        let mut result: Vec<(String, usize)> = STANDARD_LEVELS
+            .iter()
+            .filter_map(|level| counts.remove(level).map(|count| (level.to_string(), count)))
+            .collect();
+        result.extend(counts.into_iter().map(|(level, count)| (level.to_string(), count)));
+        result
+    }
+    
+    /// Set the maximum number of log entries
+    #[allow(dead_code)]
+    pub fn set_max_logs(&mut self, max_logs: usize) {
+        // An empty buffer couldn't even show the newest entry
+        self.max_logs = max_logs.max(1);
+        
+        // If the current number of logs exceeds the new maximum,
+        // remove the oldest entries until we're at the new maximum
+        self.enforce_limits();
+    }
+    
+    /// Apply a logger configuration.
+    ///
+    /// Sets the column visibility, row order and buffer size from the
+    /// config, and keeps the config for the toolbar and the table layout.
+    pub fn apply_config(&mut self, config: LoggerConfig) {
+        let config = config.clamped();
+        self.show_timestamps = config.columns.timestamps;
+        self.show_log_level = config.columns.log_level;
+        self.show_messages = config.columns.messages;
+        self.row_order = config.order;
+        self.set_max_logs(config.max_logs);
+        self.config = config;
+    }
+    
+    /// Add an application button or menu to the toolbar.
+    ///
+    /// An action with the same label is replaced, so registering again
+    /// (e.g. when the app state is rebuilt) doesn't duplicate it.
+    pub fn add_toolbar_action(&mut self, action: ToolbarAction) {
+        match self.toolbar_actions.iter_mut().find(|existing| existing.label == action.label) {
+            Some(existing) => *existing = action,
+            None => self.toolbar_actions.push(action),
+        }
+    }
+    
+    /// Call `callback` for every entry stored in the buffer.
+    ///
+    /// Calls are queued and run after the state is unlocked (see
+    /// `take_pending_hooks`), so hooks may log to the same state. A repeat
+    /// merged into a duplicate row calls the hook again with the merged row.
+    pub fn on_entry(&mut self, callback: impl Fn(&LoggerPayload) + Send + Sync + 'static) -> HookId {
+        self.hooks.add(HookTrigger::Entry, callback)
+    }
+    
+    /// Call `callback` for every stored entry of a log level or custom type
+    pub fn on_level(&mut self, level: LogType, callback: impl Fn(&LoggerPayload) + Send + Sync + 'static) -> HookId {
+        self.hooks.add(HookTrigger::Level(level), callback)
+    }
+    
+    /// Call `callback` for every stored entry whose message matches a regular expression
+    pub fn on_match(
+        &mut self,
+        pattern: &str,
+        callback: impl Fn(&LoggerPayload) + Send + Sync + 'static,
+    ) -> Result<HookId, regex::Error> {
+        let regex = regex::Regex::new(pattern)?;
+        Ok(self.hooks.add(HookTrigger::Match(regex), callback))
+    }
+    
+    /// Remove a hook, returning whether it was registered
+    pub fn remove_hook(&mut self, id: HookId) -> bool {
+        self.hooks.remove(id)
+    }
+    
+    /// Take the hook calls queued by new entries. Run them with
+    /// `PendingHooks::run` once the state is unlocked; `ReactiveEventLogger`
+    /// does this after each entry it adds and in every frame of the panel.
+    pub fn take_pending_hooks(&mut self) -> PendingHooks {
+        self.hooks.take_pending()
+    }
+    
+    /// Add a rule raising alerts for matching entries
+    pub fn add_alert_rule(&mut self, rule: AlertRule) {
+        self.alerts.add_rule(rule);
+    }
+    
+    /// Remove the toolbar action with the given label, returning whether it existed
+    pub fn remove_toolbar_action(&mut self, label: &str) -> bool {
+        let count = self.toolbar_actions.len();
+        self.toolbar_actions.retain(|action| action.label != label);
+        self.toolbar_actions.len() != count
+    }
+}
+
+/// ReactiveEventLogger
+/// 
+/// This struct is the main component for logging events in the application.
+/// It processes LoggerPayload objects which contain:
+///
+/// - Timestamp - When the log was created
+/// - LogLevel - The severity level (info, warn, debug, error)
+/// - Message - The actual content
+///
+/// The logger provides a terminal-like interface that displays logs in a table format.
+/// Users can toggle which columns to display (timestamps, log levels, messages).
+///
+/// The ReactiveEventLogger uses a shared state (ReactiveEventLoggerState) to maintain 
+/// the log entries and display settings, making it reactive to UI changes.
+pub struct ReactiveEventLogger<'a> {
+    state: &'a Dynamic<ReactiveEventLoggerState>,  // shared state of the logger panel
+    colors: Option<&'a Dynamic<LogColors>>,        // optional colors for the log messages
+    id_source: egui::Id,                           // scope of the egui ids and memory keys
+    channel: Option<String>,                       // channel of the entries logged through this logger
+}
+
+/// Id source of loggers created without `ReactiveEventLogger::id_source`
+const DEFAULT_ID_SOURCE: &str = "reactive_event_logger";
+
+/// Seconds an alert toast stays open
+const ALERT_TOAST_SECONDS: i64 = 8;
+
+/// Maximum number of alert toasts shown at once
+const MAX_ALERT_TOASTS: usize = 3;
+
+/// Factor by which the buffer may exceed `max_logs` and the memory budget
+/// while the view is frozen
+const FROZEN_GROWTH: usize = 2;
+
+/// Vertical distance between stacked toasts
+const TOAST_SPACING: f32 = 110.0;
+
+/// What the hits of a search depend on: the query, its options and the
+/// displayed entries
+#[derive(Clone, PartialEq)]
+struct SearchKey {
+    query: String,
+    regex: bool,
+    case_sensitive: bool,
+    next_sequence: u64,
+    log_count: usize,
+    edits: u64,
+    filter: LogFilter,
+    active_channel: Option<String>,
+    frozen_at: Option<u64>,
+    collapsed_groups: HashSet<u64>,
+    row_order: RowOrder,
+    pretty_json: bool,
+}
+
+/// Compiled search and its hits, reused by the search bar until the key changes
+#[derive(Clone)]
+struct SearchCache {
+    key: SearchKey,
+    matcher: Result<Option<SearchMatcher>, String>,
+    hits: std::sync::Arc<Vec<u64>>,
+}
+
+impl<'a> ReactiveEventLogger<'a> {
+    /// Create a new ReactiveEventLogger with a shared state
+    #[allow(dead_code)]
+    pub fn new(state: &'a Dynamic<ReactiveEventLoggerState>) -> Self {
+        Self {
+            state,
+            colors: None,
+            id_source: egui::Id::new(DEFAULT_ID_SOURCE),
+            channel: None,
+        }
+    }
+    
+    /// Save colors to gerber_viewer specific config directory
+    fn save_colors_for_gerber_viewer(colors: &LogColors) {
+        use std::path::PathBuf;
+        use std::fs;
+        
+        let colors = colors.clone();
+        std::thread::spawn(move || {
+            // Get config directory path for gerber_viewer
+            let config_dir = dirs::config_dir()
+                .unwrap_or_else(|| PathBuf::from("."))
+                .join("gerber_viewer");
+            
+            // Create config directory if it doesn't exist
+            if let Err(e) = fs::create_dir_all(&config_dir) {
+                eprintln!("Failed to create config directory: {}", e);
+                return;
+            }
+            
+            // Create config file path
+            let config_path = config_dir.join("log_colors.json");
+            
+            // Serialize colors to JSON
+            match serde_json::to_string_pretty(&colors) {
+                Ok(json) => {
+                    // Write JSON to file
+                    if let Err(e) = fs::write(&config_path, json) {
+                        eprintln!("Failed to write colors to {}: {}", config_path.display(), e);
+                    } else {
+                        println!("Successfully saved colors to {}", config_path.display());
+                    }
+                },
+                Err(e) => eprintln!("Failed to serialize colors: {}", e),
+            }
+        });
+    }
+    
+    /// Show the filter modal dialog
+    fn show_filter_modal(&self, ui: &mut egui::Ui) {
+        // Check if filter modal should be shown
+        let show_filter_modal = ui.ctx().memory(|mem| {
+            mem.data.get_temp::<bool>(self.id("show_logger_filter_modal")).unwrap_or(false)
+        });
+        
+        if show_filter_modal {
+            // Get reference to the state
+            let state_ref = ReactiveWidgetRef::from_dynamic(self.state);
+            
+            if let Some(state_arc) = state_ref.weak_ref.upgrade() {
+                // Get a mutable reference to the state
+                if let Ok(mut state) = state_arc.lock() {
+                    // Create a local copy of the filter for editing
+                    let mut filter = state.filter.clone();
+                    
+                    // Load saved filter settings (only once when opening the modal)
+                    filter.load_from_memory_with_id(ui.ctx(), self.id_source);
+                    
+                    // Create modal window
+                    let modal_id = self.id("logger_filter_modal");
+                    egui::Window::new("Log Filters")
                         .id(modal_id)
                         .default_size(egui::Vec2::new(300.0, 350.0))  
                         .min_size(egui::Vec2::new(250.0, 250.0))      
@@ -430,6 +1538,10 @@ impl<'a> ReactiveEventLogger<'a> {
                                     }
                                 });
                                 
+                                if ui.checkbox(&mut filter.only_bookmarked, "🔖 Only bookmarked").changed() {
+                                    changed = true;
+                                }
+                                
                                 ui.add_space(16.0);
                                 
                                 // Text filter
@@ -445,6 +1557,62 @@ impl<'a> ReactiveEventLogger<'a> {
                                 
                                 ui.label("Case-insensitive search in log messages");
                                 
+                                // Restriction to a single custom type ("Filter to this type")
+                                if let Some(identifier) = filter.only_custom_type.clone() {
+                                    ui.add_space(16.0);
+                                    ui.heading("Custom Type");
+                                    ui.add_space(4.0);
+                                    
+                                    ui.horizontal(|ui| {
+                                        ui.label(format!("Only showing CUSTOM:{}", identifier));
+                                        if ui.small_button("✖").on_hover_text("Show all custom types").clicked() {
+                                            filter.only_custom_type = None;
+                                            changed = true;
+                                        }
+                                    });
+                                }
+                                
+                                // Restriction to an operation group ("Show only this group")
+                                if let Some(group) = filter.only_group {
+                                    let name = state.find_log(group)
+                                        .map_or_else(|| format!("#{}", group), |log| log.log_message.content.value.clone());
+                                    ui.add_space(16.0);
+                                    ui.heading("Group");
+                                    ui.add_space(4.0);
+                                    
+                                    ui.horizontal(|ui| {
+                                        ui.label(format!("Only showing {}", name));
+                                        if ui.small_button("✖").on_hover_text("Show all groups").clicked() {
+                                            filter.only_group = None;
+                                            changed = true;
+                                        }
+                                    });
+                                }
+                                
+                                // Excluded messages ("Exclude messages like this")
+                                if !filter.excluded_patterns.is_empty() {
+                                    ui.add_space(16.0);
+                                    ui.heading("Excluded Messages");
+                                    ui.add_space(4.0);
+                                    
+                                    let mut remove_index = None;
+                                    for (index, pattern) in filter.excluded_patterns.iter().enumerate() {
+                                        ui.horizontal(|ui| {
+                                            if ui.small_button("✖").on_hover_text("Show these messages again").clicked() {
+                                                remove_index = Some(index);
+                                            }
+                                            ui.label(egui::RichText::new(pattern).monospace());
+                                        });
+                                    }
+                                    
+                                    if let Some(index) = remove_index {
+                                        filter.excluded_patterns.remove(index);
+                                        changed = true;
+                                    }
+                                    
+                                    ui.label("Digits match any number");
+                                }
+                                
                                 ui.add_space(16.0);
                                 
                                 // Actions
@@ -459,7 +1627,7 @@ impl<'a> ReactiveEventLogger<'a> {
                                         if ui.button("Close").clicked() {
                                             // Close the modal
                                             ui.ctx().memory_mut(|mem| {
-                                                mem.data.remove::<bool>(egui::Id::new("show_logger_filter_modal"));
+                                                mem.data.remove::<bool>(self.id("show_logger_filter_modal"));
                                             });
                                         }
                                     });
@@ -471,7 +1639,7 @@ impl<'a> ReactiveEventLogger<'a> {
                                 state.filter = filter.clone();
                                 
                                 // Save filter settings for persistence
-                                filter.save_to_memory(ui.ctx());
+                                filter.save_to_memory_with_id(ui.ctx(), self.id_source);
                             }
                         });
                 }
@@ -479,25 +1647,255 @@ impl<'a> ReactiveEventLogger<'a> {
         }
     }
     
-    /// Create a new ReactiveEventLogger with a shared state and colors
-    pub fn with_colors(state: &'a Dynamic<ReactiveEventLoggerState>, colors: &'a Dynamic<LogColors>) -> Self {
-        Self {
-            state,
-            colors: Some(colors),
+    /// Display the dropdown for selecting a filter preset
+    fn show_filter_preset_menu(&self, ui: &mut egui::Ui, state: &ReactiveEventLoggerState) {
+        let mut chosen = None;
+        
+        egui::ComboBox::from_id_salt("logger_filter_presets")
+            .selected_text(state.active_preset_label().unwrap_or_else(|| "Presets".to_string()))
+            .show_ui(ui, |ui| {
+                for preset in &state.filter_presets.presets {
+                    let active = state.active_preset.as_deref() == Some(preset.name.as_str());
+                    if ui.selectable_label(active, &preset.name).clicked() {
+                        chosen = Some(preset.name.clone());
+                    }
+                }
+                
+                ui.separator();
+                
+                if ui.button("Manage presets…").clicked() {
+                    ui.ctx().memory_mut(|mem| {
+                        mem.data.insert_temp(self.id("show_logger_presets_modal"), true);
+                    });
+                }
+            });
+        
+        if let Some(name) = chosen
+            && let Some(arc) = ReactiveWidgetRef::from_dynamic(self.state).weak_ref.upgrade() {
+            let mut state = arc.lock().unwrap();
+            if state.apply_filter_preset(&name) {
+                // Keep the filter modal in sync with the applied preset
+                state.filter.save_to_memory_with_id(ui.ctx(), self.id_source);
+            }
         }
     }
     
-    #[allow(dead_code)]
-    /// Create a new ReactiveEventLogger with the original Dynamic reference
-    /// Use this method when you have a ReactiveWidgetRef and want to create a logger
-    pub fn from_widget_ref(state: &'a Dynamic<ReactiveEventLoggerState>) -> Self {
-        Self {
-            state,
-            colors: None,
+    /// Show the modal for saving, renaming and deleting filter presets
+    fn show_filter_presets_modal(&self, ui: &mut egui::Ui) {
+        let modal_flag = self.id("show_logger_presets_modal");
+        let name_id = self.id("logger_preset_name");
+        let rename_id = self.id("logger_preset_rename");
+        
+        if !ui.ctx().memory(|mem| mem.data.get_temp::<bool>(modal_flag).unwrap_or(false)) {
+            return;
         }
-    }
-    
-    /// Add a log entry from a message string with a specific log level
+        
+        let Some(state_arc) = ReactiveWidgetRef::from_dynamic(self.state).weak_ref.upgrade() else {
+            return;
+        };
+        let Ok(mut state) = state_arc.lock() else {
+            return;
+        };
+        
+        let mut new_name = ui.ctx().data(|data| data.get_temp::<String>(name_id)).unwrap_or_default();
+        // Preset being renamed and its edited name
+        let mut renaming = ui.ctx().data(|data| data.get_temp::<(String, String)>(rename_id));
+        let mut modified = false;
+        let mut open = true;
+        
+        egui::Window::new("Filter Presets")
+            .id(self.id("logger_presets_modal"))
+            .default_size(egui::Vec2::new(320.0, 300.0))
+            .collapsible(false)
+            .resizable(true)
+            .open(&mut open)
+            .show(ui.ctx(), |ui| {
+                ui.heading("Save Current Filter");
+                ui.add_space(4.0);
+                
+                ui.horizontal(|ui| {
+                    ui.add(egui::TextEdit::singleline(&mut new_name)
+                        .hint_text("Preset name")
+                        .desired_width(180.0));
+                    
+                    let name = new_name.trim().to_string();
+                    let label = if state.filter_presets.get(&name).is_some() { "Overwrite" } else { "Save" };
+                    if ui.add_enabled(!name.is_empty(), egui::Button::new(label)).clicked() {
+                        state.save_filter_preset(&name);
+                        new_name.clear();
+                        modified = true;
+                    }
+                });
+                
+                ui.add_space(16.0);
+                ui.heading("Presets");
+                ui.add_space(4.0);
+                
+                let mut apply = None;
+                let mut delete = None;
+                let mut rename = None;
+                
+                egui::Grid::new("logger_presets_grid")
+                    .num_columns(2)
+                    .spacing([10.0, 4.0])
+                    .show(ui, |ui| {
+                        for preset in &state.filter_presets.presets {
+                            match &mut renaming {
+                                Some((original, edited)) if *original == preset.name => {
+                                    ui.text_edit_singleline(edited);
+                                    ui.horizontal(|ui| {
+                                        if ui.small_button("✔").on_hover_text("Rename").clicked() {
+                                            rename = Some((original.clone(), edited.clone()));
+                                        }
+                                        if ui.small_button("✖").on_hover_text("Cancel").clicked() {
+                                            rename = Some((String::new(), String::new()));
+                                        }
+                                    });
+                                }
+                                _ => {
+                                    ui.label(&preset.name);
+                                    ui.horizontal(|ui| {
+                                        if ui.small_button("Apply").clicked() {
+                                            apply = Some(preset.name.clone());
+                                        }
+                                        if ui.small_button("✏").on_hover_text("Rename").clicked() {
+                                            renaming = Some((preset.name.clone(), preset.name.clone()));
+                                        }
+                                        if ui.small_button("🗑").on_hover_text("Delete").clicked() {
+                                            delete = Some(preset.name.clone());
+                                        }
+                                    });
+                                }
+                            }
+                            ui.end_row();
+                        }
+                    });
+                
+                if let Some(name) = apply
+                    && state.apply_filter_preset(&name) {
+                    state.filter.save_to_memory_with_id(ui.ctx(), self.id_source);
+                }
+                if let Some(name) = delete {
+                    state.delete_filter_preset(&name);
+                    modified = true;
+                }
+                if let Some((name, new_name)) = rename {
+                    // An empty name cancels, a rejected name keeps the editor open
+                    if name.is_empty() || state.rename_filter_preset(&name, &new_name) {
+                        renaming = None;
+                        modified |= !name.is_empty();
+                    }
+                }
+            });
+        
+        if modified {
+            state.filter_presets.save();
+        }
+        
+        ui.ctx().data_mut(|data| {
+            data.insert_temp(name_id, new_name);
+            match renaming {
+                Some(renaming) => data.insert_temp(rename_id, renaming),
+                None => data.remove::<(String, String)>(rename_id),
+            }
+            if !open {
+                data.remove::<bool>(modal_flag);
+            }
+        });
+    }
+    
+    /// Create a new ReactiveEventLogger with a shared state and colors
+    pub fn with_colors(state: &'a Dynamic<ReactiveEventLoggerState>, colors: &'a Dynamic<LogColors>) -> Self {
+        Self {
+            state,
+            colors: Some(colors),
+            id_source: egui::Id::new(DEFAULT_ID_SOURCE),
+            channel: None,
+        }
+    }
+    
+    /// Get a logger writing to a named channel of the same state.
+    ///
+    /// The channel shows up as a tab in the logger panel:
+    /// `logger.channel("device").log_info("Port opened")`.
+    pub fn channel(&self, name: &str) -> Self {
+        Self {
+            state: self.state,
+            colors: self.colors,
+            id_source: self.id_source,
+            channel: Some(name.to_string()),
+        }
+    }
+    
+    /// Set the id source of this logger.
+    ///
+    /// All egui ids, modal flags and persisted filter keys of the logger are
+    /// derived from it. Give each logger shown at the same time (e.g. in
+    /// different dock tabs) its own id source so they stay independent.
+    pub fn id_source(mut self, id_source: impl std::hash::Hash) -> Self {
+        self.id_source = egui::Id::new(id_source);
+        self
+    }
+    
+    /// Configure the logger panel.
+    ///
+    /// The config is applied to the shared state when it differs from the
+    /// one applied last, so a logger built every frame with the same config
+    /// keeps the column, order and size changes the user makes in the panel.
+    pub fn config(self, config: LoggerConfig) -> Self {
+        if let Some(arc) = ReactiveWidgetRef::from_dynamic(self.state).weak_ref.upgrade() {
+            let mut state = arc.lock().unwrap();
+            let config = config.clamped();
+            if state.config != config {
+                state.apply_config(config);
+            }
+        }
+        self
+    }
+    
+    // Change the state under its lock, then run the hooks the change
+    // triggered once the lock is released, so hooks can log to this state
+    fn update_state<R>(&self, update: impl FnOnce(&mut ReactiveEventLoggerState) -> R) -> Option<R> {
+        let arc = ReactiveWidgetRef::from_dynamic(self.state).weak_ref.upgrade()?;
+        let (result, hooks) = {
+            let mut state = arc.lock().unwrap();
+            let result = update(&mut state);
+            (result, state.take_pending_hooks())
+        };
+        hooks.run();
+        Some(result)
+    }
+    
+    // Id of a widget or memory value of this logger
+    fn id(&self, name: &str) -> egui::Id {
+        self.id_source.with(name)
+    }
+    
+    // Keyboard shortcuts only act on the logger under the pointer, or on the
+    // logger whose search field or command line has focus, so several
+    // loggers in one window don't all react to the same key press
+    fn wants_shortcuts(&self, ui: &egui::Ui) -> bool {
+        let rect = ui.data(|data| data.get_temp(self.id("logger_rect"))).unwrap_or(ui.max_rect());
+        let focused = ui.memory(|mem| mem.focused());
+        ui.rect_contains_pointer(rect)
+            || focused == Some(self.id("logger_search_field"))
+            || focused == Some(self.id("logger_command_field"))
+    }
+    
+    #[allow(dead_code)]
+    /// Create a new ReactiveEventLogger with the original Dynamic reference
+    /// Use this method when you have a ReactiveWidgetRef and want to create a logger
+    pub fn from_widget_ref(state: &'a Dynamic<ReactiveEventLoggerState>) -> Self {
+        Self {
+            state,
+            colors: None,
+            id_source: egui::Id::new(DEFAULT_ID_SOURCE),
+            channel: None,
+        }
+    }
+    
+    /// Add a log entry from a message string with a specific log level
+    #[track_caller]
     pub fn add_log(&self, level: &str, message: &str) {
         let mut payload = LoggerPayload::new();
     
@@ -593,6 +1991,7 @@ impl<'a> ReactiveEventLogger<'a> {
     
         payload.message(message.to_string())
                .update();
+        payload.location = Some(std::panic::Location::caller());
     
         self.process_log(&payload);
     }
@@ -609,18 +2008,57 @@ impl<'a> ReactiveEventLogger<'a> {
 
     /// Processes a new log entry and adds it to the shared state
     /// Process a log payload and add it to the logger state
+    #[track_caller]
     pub fn process_log(&self, log: &LoggerPayload) {
-        if let Some(state_arc) = ReactiveWidgetRef::from_dynamic(self.state).weak_ref.upgrade() {
-            let mut state = state_arc.lock().unwrap();
-            // Only add non-empty logs
-            if !log.timestamp.value.value.is_empty() {
-                state.add_log(log.clone());
+        // Only add non-empty logs
+        if !log.timestamp.value.value.is_empty() {
+            let mut log = log.clone();
+            // Record where the log came from unless the payload already knows
+            if log.location.is_none() {
+                log.location = Some(std::panic::Location::caller());
+            }
+            if log.channel.is_none() {
+                log.channel.clone_from(&self.channel);
             }
+            self.update_state(|state| state.add_log(log));
         }
     }
     
+    /// Add a live progress entry for an operation of `total` units.
+    ///
+    /// The returned handle updates the entry in place (`set`) and turns it
+    /// into a normal entry when finished (`finish_ok` / `finish_err`). It
+    /// can be moved to the thread doing the work.
+    #[track_caller]
+    pub fn progress(&self, label: &str, total: u64) -> ProgressHandle {
+        let mut payload = LoggerPayload::new();
+        payload.info().message(label.to_string()).update();
+        payload.location = Some(std::panic::Location::caller());
+        payload.channel.clone_from(&self.channel);
+        
+        let sequence = self.update_state(|state| state.start_progress(payload, total)).unwrap_or(0);
+        ProgressHandle::new(self.state.clone(), sequence)
+    }
+    
+    /// Open an operation group with a header entry.
+    ///
+    /// Entries logged until the returned guard is dropped are nested under
+    /// the header, which shows them as a collapsible group. On close the
+    /// header records the total duration and the worst severity.
+    #[track_caller]
+    pub fn group(&self, name: &str) -> GroupGuard {
+        let mut payload = LoggerPayload::new();
+        payload.info().message(name.to_string()).update();
+        payload.location = Some(std::panic::Location::caller());
+        payload.channel.clone_from(&self.channel);
+        
+        let sequence = self.update_state(|state| state.begin_group(payload)).unwrap_or(0);
+        GroupGuard::new(self.state.clone(), sequence)
+    }
+    
     #[allow(dead_code)]
     /// Create and add a simple message-only log with the given content
+    #[track_caller]
     pub fn log_message(&self, content: &str) {
         let mut message = LoggerPayload::new();
         message.as_message_only()
@@ -631,26 +2069,31 @@ impl<'a> ReactiveEventLogger<'a> {
     }
     
     /// Create and add an info level log with the given content
+    #[track_caller]
     pub fn log_info(&self, content: &str) {
         self.add_log("info", content);
     }
     
     /// Create and add a warning level log with the given content
+    #[track_caller]
     pub fn log_warning(&self, content: &str) {
         self.add_log("warning", content);
     }
     
     /// Create and add a debug level log with the given content
+    #[track_caller]
     pub fn log_debug(&self, content: &str) {
         self.add_log("debug", content);
     }
     
     /// Create and add an error level log with the given content
+    #[track_caller]
     pub fn log_error(&self, content: &str) {
         self.add_log("error", content);
     }
     
     /// Create and add a custom log with the given type identifier and content
+    #[track_caller]
     pub fn log_custom(&self, custom_type: &str, content: &str) {
         self.add_log(&format!("custom:{}", custom_type), content);
     }
@@ -665,29 +2108,8 @@ impl<'a> ReactiveEventLogger<'a> {
         
         // Process logs chronologically (oldest first)
         for log in state.logs.iter() {
-            let mut line = String::new();
-            
-            // Add timestamp if available
-            if !log.timestamp.value.value.is_empty() {
-                line.push_str(&format!("[{}] ", log.timestamp.value.value));
-            }
-            
-            // Add log level if available
-            if !log.log_level.info.value.is_empty() {
-                line.push_str(&format!("[{}] ", log.log_level.info.value));
-            } else if !log.log_level.debug.value.is_empty() {
-                line.push_str(&format!("[{}] ", log.log_level.debug.value));
-            } else if !log.log_level.warning.value.is_empty() {
-                line.push_str(&format!("[{}] ", log.log_level.warning.value));
-            } else if !log.log_level.error.value.is_empty() {
-                line.push_str(&format!("[{}] ", log.log_level.error.value));
-            }
-            
-            // Add message
-            line.push_str(&log.log_message.content.value);
-            line.push('\n');
-            
-            log_content.push_str(&line);
+            log_content.push_str(&format_log_line(log));
+            log_content.push('\n');
         }
         
         log_content
@@ -696,19 +2118,143 @@ impl<'a> ReactiveEventLogger<'a> {
     /// Save logs to a file
     #[allow(dead_code)]
     fn save_logs_to_file(&self, path: &std::path::Path) -> Result<(), std::io::Error> {
-        if let Some(state_arc) = ReactiveWidgetRef::from_dynamic(self.state).weak_ref.upgrade() {
-            if let Ok(state) = state_arc.lock() {
-                let log_content = self.format_logs_for_export(&state);
-                std::fs::write(path, log_content)?;
-                return Ok(());
-            }
+        if let Some(state_arc) = ReactiveWidgetRef::from_dynamic(self.state).weak_ref.upgrade()
+            && let Ok(state) = state_arc.lock() {
+            let log_content = self.format_logs_for_export(&state);
+            std::fs::write(path, log_content)?;
+            return Ok(());
         }
         
-        Err(std::io::Error::new(std::io::ErrorKind::Other, "Failed to access log data"))
+        Err(std::io::Error::other("Failed to access log data"))
     }
 
-    /// Display the logger UI
+    /// Display the logger UI.
+    ///
+    /// While the logger is popped out into its own window, a placeholder
+    /// with a button to dock it back is shown instead.
     pub fn show(&self, ui: &mut egui::Ui) {
+        // Also runs the hooks of entries added to the state directly
+        let popped_out = self.update_state(|state| state.popped_out).unwrap_or(false);
+        
+        if popped_out {
+            self.show_popped_out(ui);
+        } else {
+            self.show_tracked(ui);
+        }
+    }
+    
+    /// Show the placeholder of a popped out logger and its viewport window
+    fn show_popped_out(&self, ui: &mut egui::Ui) {
+        let state_ref = ReactiveWidgetRef::from_dynamic(self.state);
+        
+        ui.vertical_centered(|ui| {
+            ui.add_space(20.0);
+            ui.label("The logger is shown in a separate window.");
+            if ui.button("⧉ Dock back").clicked()
+                && let Some(arc) = state_ref.weak_ref.upgrade() {
+                arc.lock().unwrap().popped_out = false;
+            }
+        });
+        
+        self.show_viewport(ui.ctx());
+    }
+    
+    /// Show the window of a popped out logger.
+    ///
+    /// egui closes a viewport as soon as a frame passes without it being
+    /// shown. `show` shows it along with the panel, but when the panel can
+    /// be hidden (e.g. in a background dock tab) the application calls this
+    /// once per frame, like `show_alert_toasts`, to keep the window open.
+    /// The window is shown once per frame, whichever is called first.
+    pub fn show_viewport(&self, ctx: &egui::Context) {
+        let popped_out = ReactiveWidgetRef::from_dynamic(self.state)
+            .weak_ref
+            .upgrade()
+            .is_some_and(|arc| arc.lock().unwrap().popped_out);
+        if !popped_out {
+            return;
+        }
+        
+        let pass = ctx.cumulative_pass_nr();
+        let shown_id = self.id("logger_popout_shown_pass");
+        let shown = ctx.data_mut(|data| {
+            let shown = data.get_temp::<u64>(shown_id) == Some(pass);
+            data.insert_temp(shown_id, pass);
+            shown
+        });
+        if shown {
+            return;
+        }
+        
+        // Open the window where it was last closed
+        let geometry_id = self.id("logger_popout_geometry");
+        let geometry = ctx.data_mut(|data| data.get_persisted::<egui::Rect>(geometry_id));
+        let mut builder = egui::ViewportBuilder::default().with_title("Logger");
+        builder = match geometry {
+            Some(rect) => builder.with_position(rect.min).with_inner_size(rect.size()),
+            None => builder.with_inner_size([900.0, 500.0]),
+        };
+        
+        // The deferred viewport outlives this frame, so it gets its own
+        // handles to the shared state and colors
+        let state = self.state.clone();
+        let colors = self.colors.cloned();
+        let id_source = self.id_source;
+        let channel = self.channel.clone();
+        
+        ctx.show_viewport_deferred(egui::ViewportId(self.id("logger_popout")), builder, move |ctx, class| {
+            let logger = ReactiveEventLogger {
+                state: &state,
+                colors: colors.as_ref(),
+                id_source,
+                channel: channel.clone(),
+            };
+            let dock_back = || {
+                if let Some(arc) = ReactiveWidgetRef::from_dynamic(&state).weak_ref.upgrade() {
+                    arc.lock().unwrap().popped_out = false;
+                }
+                ctx.request_repaint_of(egui::ViewportId::ROOT);
+            };
+            
+            if class == egui::ViewportClass::Embedded {
+                // The backend can't open another window, so use an egui window
+                let mut open = true;
+                egui::Window::new("Logger")
+                    .id(logger.id("logger_popout_window"))
+                    .default_size([900.0, 500.0])
+                    .open(&mut open)
+                    .show(ctx, |ui| logger.show_tracked(ui));
+                if !open {
+                    dock_back();
+                }
+                return;
+            }
+            
+            egui::CentralPanel::default().show(ctx, |ui| logger.show_tracked(ui));
+            
+            // Remember the window geometry, and dock back when the window is closed
+            let (outer, inner, close_requested) = ctx.input(|i| {
+                (i.viewport().outer_rect, i.viewport().inner_rect, i.viewport().close_requested())
+            });
+            if let (Some(outer), Some(inner)) = (outer, inner) {
+                let rect = egui::Rect::from_min_size(outer.min, inner.size());
+                ctx.data_mut(|data| data.insert_persisted(geometry_id, rect));
+            }
+            if close_requested {
+                dock_back();
+            }
+        });
+    }
+    
+    /// Show the logger contents and remember the area they cover, which
+    /// decides next frame whether keyboard shortcuts go to this logger
+    fn show_tracked(&self, ui: &mut egui::Ui) {
+        let rect = ui.scope(|ui| self.show_contents(ui)).response.rect;
+        ui.data_mut(|data| data.insert_temp(self.id("logger_rect"), rect));
+    }
+    
+    /// Display the full logger UI: toolbar, display options and the table
+    fn show_contents(&self, ui: &mut egui::Ui) {
         // Get a reference to the state
         let mut state_ref = ReactiveWidgetRef::from_dynamic(self.state);
         
@@ -733,71 +2279,87 @@ impl<'a> ReactiveEventLogger<'a> {
             }
         };
         
-        ui.vertical(|ui| {
+        let shortcuts = self.wants_shortcuts(ui);
+        
+        // Summarize rate limited entries even when no new entries arrive
+        if state_value.rate_limiter.has_pending() {
+            self.update_state(|state| state.flush_rate_limit_summaries());
+            ui.ctx().request_repaint_after(state_value.rate_limiter.summary_interval);
+        }
+        
+        // The panel is shown, so everything in it has been seen
+        if state_value.unread.total() > 0
+            && let Some(arc) = state_ref.weak_ref.upgrade() {
+            arc.lock().unwrap().mark_all_read();
+        }
+        
+        // Keep live progress bars and their ETA moving
+        if state_value.has_active_progress() {
+            ui.ctx().request_repaint_after(std::time::Duration::from_millis(100));
+        }
+        
+        // Scope the ids of all widgets to this logger
+        let scope = egui::UiBuilder::new()
+            .id_salt(self.id_source)
+            .layout(egui::Layout::top_down(egui::Align::Min));
+        ui.scope_builder(scope, |ui| {
             // Top row with buffer status and clear button
             ui.horizontal(|ui| {
                 // Show buffer status
                 ui.label(format!("Logs: {}/{}", state_value.log_count(), state_value.max_logs));
                 
+                // Show the memory used if a budget is set
+                if let Some(budget) = &state_value.memory_budget {
+                    ui.label(format!("Memory: {}/{} MB",
+                        format_megabytes(state_value.memory_usage()),
+                        format_megabytes(budget.max_bytes)));
+                }
+                
+                // Show the occupancy per level, and of the retention rules on hover
+                let level_counts = state_value.level_counts();
+                if !level_counts.is_empty() {
+                    let occupancy = level_counts
+                        .iter()
+                        .map(|(level, count)| format!("{} {}", level.trim_start_matches("CUSTOM:"), count))
+                        .collect::<Vec<_>>()
+                        .join(" · ");
+                    let response = ui.label(egui::RichText::new(occupancy).weak());
+                    if !state_value.retention.is_empty() {
+                        let rules = state_value.retention
+                            .iter()
+                            .map(|rule| rule.describe(&state_value.logs))
+                            .collect::<Vec<_>>()
+                            .join("\n");
+                        response.on_hover_text(format!("Retention rules:\n{}", rules));
+                    }
+                }
+                
+                // Show how many entries the rate limits have dropped
+                let rate_metrics = state_value.rate_limiter.metrics();
+                if rate_metrics.suppressed > 0 {
+                    ui.label(egui::RichText::new(format!("Dropped: {}", format_count(rate_metrics.suppressed))).weak())
+                        .on_hover_text(format!(
+                            "{} entries dropped by rate limits, {} admitted",
+                            format_count(rate_metrics.suppressed),
+                            format_count(rate_metrics.admitted),
+                        ));
+                }
+                
                 // Add spacing to push buttons to the right
                 ui.with_layout(egui::Layout::right_to_left(egui::Align::Center), |ui| {
-                    // Clear logs button
-                    if ui.button("Clear Logs").clicked() {
-                        // Clear logs if button clicked
-                        if let Some(arc) = state_ref.weak_ref.upgrade() {
-                            let mut state = arc.lock().unwrap();
-                            state.clear_logs();
+                    // Configured buttons, added from the right
+                    for (index, button) in state_value.config.toolbar.iter().rev().enumerate() {
+                        // Add small spacing between buttons
+                        if index > 0 {
+                            ui.add_space(8.0);
                         }
+                        self.show_toolbar_button(ui, *button, state_value);
                     }
                     
-                    // Add small spacing between buttons
-                    ui.add_space(8.0);
-                    
-                    // Add Save Logs button
-                    if ui.button("💾 Save Logs").clicked() {
-                        // Set a flag to open the save dialog
-                        ui.ctx().memory_mut(|mem| {
-                            mem.data.insert_temp(egui::Id::new("show_save_logs_dialog"), true);
-                        });
-                    }
-                    
-                    // Add small spacing between buttons
-                    ui.add_space(8.0);
-                    
-                    // Add Logger Colors button
-                    if ui.button("🎨 Logger Colors").clicked() {
-                        // Set a flag to open the color dialog
-                        ui.ctx().memory_mut(|mem| {
-                            mem.data.insert_temp(egui::Id::new("show_logger_colors_modal"), true);
-                        });
-                    }
-                    
-                    // Add small spacing between buttons
-                    ui.add_space(8.0);
-                    
-                    // Add Filter button
-                    let filter_button_text = if is_any_filter_active(&state_value.filter) {
-                        "🔍 Filters (Active)"
-                    } else {
-                        "🔍 Filters"
-                    };
-                    
-                    if ui.button(filter_button_text).clicked() {
-                        // Signal to open the filter modal
-                        ui.ctx().memory_mut(|mem| {
-                            mem.data.insert_temp(egui::Id::new("show_logger_filter_modal"), true);
-                        });
-                    }
-                    
-                    // Add small spacing between buttons
-                    ui.add_space(8.0);
-                    
-                    // Add System Info button (we keep this in the UI, but application should implement it)
-                    if ui.button("📊 System Info").clicked() {
-                        // Signal to the application to show system info
-                        ui.ctx().memory_mut(|mem| {
-                            mem.data.insert_temp(egui::Id::new("show_system_info"), true);
-                        });
+                    // Application actions left of the built-in buttons
+                    for action in state_value.toolbar_actions.iter().rev() {
+                        ui.add_space(8.0);
+                        action.show(ui, self.state);
                     }
                 });
             });
@@ -816,10 +2378,9 @@ impl<'a> ReactiveEventLogger<'a> {
                 // Timestamps checkbox
                 if ui.checkbox(&mut show_timestamps, "Timestamps").changed() {
                     // Update the shared state if changed
-                    if let Some(arc) = state_ref.weak_ref.upgrade() {
-                        if let Ok(mut state) = arc.lock() {
-                            state.show_timestamps = show_timestamps;
-                        }
+                    if let Some(arc) = state_ref.weak_ref.upgrade()
+                        && let Ok(mut state) = arc.lock() {
+                        state.show_timestamps = show_timestamps;
                     }
                 }
                 
@@ -840,10 +2401,246 @@ impl<'a> ReactiveEventLogger<'a> {
                         state.show_messages = show_messages;
                     }
                 }
+
+                ui.separator();
+
+                // Strip ANSI checkbox
+                let mut strip_ansi = state_value.strip_ansi;
+                if ui.checkbox(&mut strip_ansi, "Strip ANSI")
+                    .on_hover_text("Remove terminal color codes instead of rendering them")
+                    .changed()
+                    && let Some(arc) = state_ref.weak_ref.upgrade() {
+                    let mut state = arc.lock().unwrap();
+                    state.strip_ansi = strip_ansi;
+                }
+                
+                // Pretty JSON checkbox
+                let mut pretty_json = state_value.pretty_json;
+                if ui.checkbox(&mut pretty_json, "Pretty JSON")
+                    .on_hover_text("Show JSON messages as compact colored previews and trees")
+                    .changed()
+                    && let Some(arc) = state_ref.weak_ref.upgrade() {
+                    let mut state = arc.lock().unwrap();
+                    state.pretty_json = pretty_json;
+                }
+                
+                ui.separator();
+                
+                // Follow toggle and row order
+                let mut follow = state_value.follow;
+                if ui.toggle_value(&mut follow, "⏵ Follow")
+                    .on_hover_text("Keep the newest entries in view (pauses when scrolling away)")
+                    .changed()
+                    && let Some(arc) = state_ref.weak_ref.upgrade() {
+                    let mut state = arc.lock().unwrap();
+                    if follow {
+                        state.resume_follow();
+                    } else {
+                        state.pause_follow();
+                    }
+                }
+                
+                // Freeze toggle, showing how much arrived since freezing
+                let mut frozen = state_value.frozen_at.is_some();
+                let freeze_text = match state_value.frozen_at {
+                    Some(_) => format!("❄ Frozen (+{})", format_count(state_value.received_since_freeze())),
+                    None => "❄ Freeze".to_string(),
+                };
+                let freeze_hover = match state_value.frozen_at {
+                    Some(frozen_at) => format!("Showing entries before #{}; click to unfreeze", frozen_at),
+                    None => "Stop the view from changing while new entries keep arriving".to_string(),
+                };
+                if ui.toggle_value(&mut frozen, freeze_text)
+                    .on_hover_text(freeze_hover)
+                    .changed()
+                    && let Some(arc) = state_ref.weak_ref.upgrade() {
+                    let mut state = arc.lock().unwrap();
+                    if frozen {
+                        state.freeze();
+                    } else {
+                        state.unfreeze();
+                    }
+                }
+                
+                let mut row_order = state_value.row_order;
+                egui::ComboBox::from_id_salt("logger_row_order")
+                    .selected_text(match row_order {
+                        RowOrder::NewestFirst => "Newest first",
+                        RowOrder::OldestFirst => "Oldest first",
+                    })
+                    .show_ui(ui, |ui| {
+                        ui.selectable_value(&mut row_order, RowOrder::NewestFirst, "Newest first");
+                        ui.selectable_value(&mut row_order, RowOrder::OldestFirst, "Oldest first");
+                    });
+                if row_order != state_value.row_order
+                    && let Some(arc) = state_ref.weak_ref.upgrade() {
+                    let mut state = arc.lock().unwrap();
+                    state.row_order = row_order;
+                    state.resume_follow();
+                }
+                
+                // Bookmark list to jump to a bookmarked entry
+                let bookmarks = state_value.bookmarked_logs();
+                ui.menu_button(format!("🔖 Bookmarks ({})", bookmarks.len()), |ui| {
+                    if bookmarks.is_empty() {
+                        ui.label("No bookmarks yet. Click the gutter of a row or press Ctrl+B.");
+                    }
+                    for log in &bookmarks {
+                        let text = format!("{}  {}", log.created_at.format("%H:%M:%S"), log.log_message.content.value);
+                        let response = ui.button(shorten_text(&text, 60));
+                        let response = match &log.note {
+                            Some(note) => response.on_hover_text(note),
+                            None => response,
+                        };
+                        if response.clicked() {
+                            self.jump_to_entry(ui.ctx(), log.sequence);
+                            ui.close_menu();
+                        }
+                    }
+                });
+                
+                // Notification center with the recent alerts
+                let unseen = state_value.alerts.unseen_count();
+                let alerts_text = if unseen > 0 {
+                    format!("🔔 Alerts ({})", unseen)
+                } else {
+                    "🔔 Alerts".to_string()
+                };
+                let alerts_menu = ui.menu_button(alerts_text, |ui| {
+                    if state_value.alerts.raised.is_empty() {
+                        ui.label("No alerts yet. Alert rules raise them for matching entries.");
+                        return;
+                    }
+                    egui::ScrollArea::vertical().max_height(300.0).show(ui, |ui| {
+                        for alert in state_value.alerts.raised.iter().rev() {
+                            let text = format!("{}  {}: {}", alert.raised_at.format("%H:%M:%S"), alert.rule, alert.message);
+                            let available = state_value.find_log(alert.sequence).is_some();
+                            if ui.add_enabled(available, egui::Button::new(shorten_text(&text, 80)))
+                                .on_disabled_hover_text("The entry is no longer in the buffer")
+                                .clicked() {
+                                self.jump_to_entry(ui.ctx(), alert.sequence);
+                                ui.close_menu();
+                            }
+                        }
+                    });
+                    ui.separator();
+                    if ui.button("Clear alerts").clicked() {
+                        if let Some(arc) = state_ref.weak_ref.upgrade() {
+                            arc.lock().unwrap().alerts.clear();
+                        }
+                        ui.close_menu();
+                    }
+                });
+                
+                // Opening the notification center marks the alerts as seen
+                if alerts_menu.inner.is_some()
+                    && unseen > 0
+                    && let Some(arc) = state_ref.weak_ref.upgrade() {
+                    arc.lock().unwrap().alerts.mark_seen();
+                }
+                
+                ui.separator();
+                
+                // Detail pane placement
+                let mut detail_pane = state_value.detail_pane;
+                ui.label("Details:");
+                egui::ComboBox::from_id_salt("logger_detail_pane_position")
+                    .selected_text(format!("{:?}", detail_pane))
+                    .show_ui(ui, |ui| {
+                        ui.selectable_value(&mut detail_pane, DetailPane::Hidden, "Hidden");
+                        ui.selectable_value(&mut detail_pane, DetailPane::Right, "Right");
+                        ui.selectable_value(&mut detail_pane, DetailPane::Bottom, "Bottom");
+                    });
+                if detail_pane != state_value.detail_pane
+                    && let Some(arc) = state_ref.weak_ref.upgrade() {
+                    let mut state = arc.lock().unwrap();
+                    state.detail_pane = detail_pane;
+                }
+                
+                // Split view to compare two filtered streams
+                let mut split_view = state_value.split_view;
+                ui.label("Split:");
+                egui::ComboBox::from_id_salt("logger_split_view")
+                    .selected_text(match split_view {
+                        SplitView::Off => "Off",
+                        SplitView::SideBySide => "Side by side",
+                        SplitView::Stacked => "Stacked",
+                    })
+                    .show_ui(ui, |ui| {
+                        ui.selectable_value(&mut split_view, SplitView::Off, "Off");
+                        ui.selectable_value(&mut split_view, SplitView::SideBySide, "Side by side");
+                        ui.selectable_value(&mut split_view, SplitView::Stacked, "Stacked");
+                    });
+                if split_view != state_value.split_view
+                    && let Some(arc) = state_ref.weak_ref.upgrade() {
+                    let mut state = arc.lock().unwrap();
+                    state.split_view = split_view;
+                }
             });
             
+            // Display the channel tabs if entries are logged to channels
+            if !state_value.channels.is_empty() {
+                self.show_channel_tabs(ui, state_value);
+            }
+            
+            // Display the search bar (Ctrl+F)
+            let search = self.show_search_bar(ui, state_value, shortcuts);
+            
+            // Display the command line at the bottom, if enabled
+            if state_value.config.command_line {
+                self.show_command_line(ui, state_value);
+            }
+            
+            // Display the detail pane first so the table gets the remaining space
+            self.show_detail_pane(ui, state_value);
+            
             // Display terminal content using the cached state value
-            self.show_event_log_content(ui, state_value);
+            // Scroll to an entry once, after navigating to a search hit or bookmark
+            let scroll_target = ui.ctx().data_mut(|data| data.remove_temp::<u64>(self.id("logger_scroll_to_entry")));
+            
+            // Display terminal content, in two panes for a split view
+            match state_value.split_view {
+                SplitView::Off => {
+                    self.show_event_log_content(ui, state_value, 0, search.as_ref(), scroll_target);
+                }
+                SplitView::SideBySide => {
+                    ui.columns(2, |columns| {
+                        for (pane, ui) in columns.iter_mut().enumerate() {
+                            self.show_split_pane(ui, state_value, pane, search.as_ref(), scroll_target);
+                        }
+                    });
+                }
+                SplitView::Stacked => {
+                    let pane_size = egui::vec2(ui.available_width(), ui.available_height() / 2.0);
+                    for pane in 0..2 {
+                        ui.allocate_ui(pane_size, |ui| {
+                            ui.set_min_size(pane_size);
+                            self.show_split_pane(ui, state_value, pane, search.as_ref(), scroll_target);
+                        });
+                    }
+                }
+            }
+            
+            // Ctrl+C copies the selected rows unless a text field has focus
+            let copy_requested = ui.input(|i| i.events.iter().any(|e| matches!(e, egui::Event::Copy)));
+            if copy_requested
+                && shortcuts
+                && !state_value.selected.is_empty()
+                && ui.ctx().memory(|mem| mem.focused().is_none()) {
+                ui.ctx().copy_text(state_value.selected_logs_text());
+            }
+            
+            // Ctrl+B toggles the bookmark of the selected rows
+            if shortcuts
+                && !state_value.selected.is_empty()
+                && ui.ctx().memory(|mem| mem.focused().is_none())
+                && ui.input_mut(|i| i.consume_key(egui::Modifiers::COMMAND, egui::Key::B))
+                && let Some(arc) = state_ref.weak_ref.upgrade() {
+                let mut state = arc.lock().unwrap();
+                for sequence in &state_value.selected {
+                    state.toggle_bookmark(*sequence);
+                }
+            }
             
             // Show color picker modal if needed
             self.show_color_picker_modal(ui);
@@ -851,6 +2648,9 @@ impl<'a> ReactiveEventLogger<'a> {
             // Show filter modal if needed
             self.show_filter_modal(ui);
             
+            // Show filter presets modal if needed
+            self.show_filter_presets_modal(ui);
+            
             // Show save dialog if needed
             self.show_save_dialog(ui);
         });
@@ -860,49 +2660,56 @@ impl<'a> ReactiveEventLogger<'a> {
     fn show_save_dialog(&self, ui: &mut egui::Ui) {
         // Check if save dialog should be shown
         let show_save_dialog = ui.ctx().memory(|mem| {
-            mem.data.get_temp::<bool>(egui::Id::new("show_save_logs_dialog")).unwrap_or(false)
+            mem.data.get_temp::<bool>(self.id("show_save_logs_dialog")).unwrap_or(false)
         });
         
         if show_save_dialog {
             // Use a future to handle the async file dialog
             ui.ctx().memory_mut(|mem| {
                 // Clear the flag first to prevent duplicate dialogs
-                mem.data.remove::<bool>(egui::Id::new("show_save_logs_dialog"));
+                mem.data.remove::<bool>(self.id("show_save_logs_dialog"));
                 
                 // Create a new thread to show the file dialog
                 // This avoids blocking the UI thread
                 let ctx = ui.ctx().clone();
                 let state_clone = self.state.clone();
+                let error_id = self.id("save_logs_error");
+                let success_id = self.id("save_logs_success");
                 std::thread::spawn(move || {
                     // Show native file dialog
                     if let Some(path) = rfd::FileDialog::new()
                         .add_filter("Text files", &["txt"])
                         .add_filter("Log files", &["log"])
+                        .add_filter("JSON files (with bookmarks and notes)", &["json"])
                         .add_filter("All files", &["*"])
                         .set_file_name("logs.txt")
                         .set_title("Save Log File")
                         .save_file() {
                         
                         // Try to save the file
-                        if let Some(state_arc) = ReactiveWidgetRef::from_dynamic(&state_clone).weak_ref.upgrade() {
-                            if let Ok(state) = state_arc.lock() {
-                                let reactive_logger = ReactiveEventLogger::new(&state_clone);
-                                let log_content = reactive_logger.format_logs_for_export(&state);
-                                
-                                // Save the logs to the file
-                                if let Err(err) = std::fs::write(&path, log_content) {
-                                    // On error, set a flag to show an error message
-                                    ctx.memory_mut(|mem| {
-                                        mem.data.insert_temp(egui::Id::new("save_logs_error"), 
-                                            format!("Failed to save logs: {}", err));
-                                    });
-                                } else {
-                                    // On success, set a flag to show a success message
-                                    ctx.memory_mut(|mem| {
-                                        mem.data.insert_temp(egui::Id::new("save_logs_success"), 
-                                            format!("Logs saved to: {}", path.display()));
-                                    });
-                                }
+                        if let Some(state_arc) = ReactiveWidgetRef::from_dynamic(&state_clone).weak_ref.upgrade()
+                            && let Ok(state) = state_arc.lock() {
+                            let reactive_logger = ReactiveEventLogger::new(&state_clone);
+                            let is_json = path.extension().is_some_and(|extension| extension == "json");
+                            let log_content = if is_json {
+                                serde_json::to_string_pretty(&state.to_json()).unwrap_or_default()
+                            } else {
+                                reactive_logger.format_logs_for_export(&state)
+                            };
+                            
+                            // Save the logs to the file
+                            if let Err(err) = std::fs::write(&path, log_content) {
+                                // On error, set a flag to show an error message
+                                ctx.memory_mut(|mem| {
+                                    mem.data.insert_temp(error_id, 
+                                        format!("Failed to save logs: {}", err));
+                                });
+                            } else {
+                                // On success, set a flag to show a success message
+                                ctx.memory_mut(|mem| {
+                                    mem.data.insert_temp(success_id, 
+                                        format!("Logs saved to: {}", path.display()));
+                                });
                             }
                         }
                     }
@@ -915,14 +2722,10 @@ impl<'a> ReactiveEventLogger<'a> {
         
         // Show success message if present
         if let Some(success_msg) = ui.ctx().memory(|mem| {
-            mem.data.get_temp::<String>(egui::Id::new("save_logs_success"))
+            mem.data.get_temp::<String>(self.id("save_logs_success"))
         }) {
             // Create a temporary success notification
-            let toast = egui::Window::new("✓ Success")
-                .collapsible(false)
-                .resizable(false)
-                .anchor(egui::Align2::RIGHT_BOTTOM, [10.0, 10.0])
-                .fixed_size([300.0, 80.0]);
+            let toast = self.toast_window("✓ Success", self.id("save_logs_success_toast"), 0);
                 
             toast.show(ui.ctx(), |ui| {
                 ui.vertical_centered(|ui| {
@@ -932,7 +2735,7 @@ impl<'a> ReactiveEventLogger<'a> {
                     
                     if ui.button("Close").clicked() {
                         ui.ctx().memory_mut(|mem| {
-                            mem.data.remove::<String>(egui::Id::new("save_logs_success"));
+                            mem.data.remove::<String>(self.id("save_logs_success"));
                         });
                     }
                 });
@@ -944,14 +2747,12 @@ impl<'a> ReactiveEventLogger<'a> {
         
         // Show error message if present
         if let Some(error_msg) = ui.ctx().memory(|mem| {
-            mem.data.get_temp::<String>(egui::Id::new("save_logs_error"))
+            mem.data.get_temp::<String>(self.id("save_logs_error"))
         }) {
             // Create a temporary error notification
-            let toast = egui::Window::new("❌ Error")
-                .collapsible(false)
-                .resizable(false)
-                .anchor(egui::Align2::RIGHT_BOTTOM, [10.0, 10.0])
-                .fixed_size([300.0, 80.0]);
+            // Above the success toast if both are shown
+            let slot = self.save_toast_count(ui.ctx()) - 1;
+            let toast = self.toast_window("❌ Error", self.id("save_logs_error_toast"), slot);
                 
             toast.show(ui.ctx(), |ui| {
                 ui.vertical_centered(|ui| {
@@ -961,7 +2762,7 @@ impl<'a> ReactiveEventLogger<'a> {
                     
                     if ui.button("Close").clicked() {
                         ui.ctx().memory_mut(|mem| {
-                            mem.data.remove::<String>(egui::Id::new("save_logs_error"));
+                            mem.data.remove::<String>(self.id("save_logs_error"));
                         });
                     }
                 });
@@ -978,12 +2779,12 @@ impl<'a> ReactiveEventLogger<'a> {
         if let Some(colors_dynamic) = self.colors {
             // Check if modal should be shown
             let show_modal = ui.ctx().memory(|mem| {
-                mem.data.get_temp::<bool>(egui::Id::new("show_logger_colors_modal")).unwrap_or(false)
+                mem.data.get_temp::<bool>(self.id("show_logger_colors_modal")).unwrap_or(false)
             });
             
             if show_modal {
                 // Create modal window
-                let modal_id = egui::Id::new("logger_colors_modal");
+                let modal_id = self.id("logger_colors_modal");
                 egui::Window::new("Logger Colors")
                     .id(modal_id)
                     .default_size(egui::Vec2::new(450.0, 650.0))  // Adjusted height for better initial view
@@ -999,7 +2800,7 @@ impl<'a> ReactiveEventLogger<'a> {
                         
                         // Store sync state in memory to persist between frames
                         let mut sync_colors = ui.ctx().memory_mut(|mem| {
-                            mem.data.get_temp::<bool>(egui::Id::new("logger_colors_sync_mode"))
+                            mem.data.get_temp::<bool>(self.id("logger_colors_sync_mode"))
                                 .unwrap_or(false)
                         });
                         
@@ -1009,7 +2810,7 @@ impl<'a> ReactiveEventLogger<'a> {
                                 if ui.checkbox(&mut sync_colors, "Match Log & Messages").changed() {
                                     // Update sync state in memory
                                     ui.ctx().memory_mut(|mem| {
-                                        mem.data.insert_temp(egui::Id::new("logger_colors_sync_mode"), sync_colors);
+                                        mem.data.insert_temp(self.id("logger_colors_sync_mode"), sync_colors);
                                     });
                                 }
                             });
@@ -1312,31 +3113,87 @@ impl<'a> ReactiveEventLogger<'a> {
                                                 changed |= ui.color_edit_button_srgba(&mut colors.success).changed();
                                             });
                                         });
+                                        
+                                        ui.horizontal(|ui| {
+                                            ui.with_layout(egui::Layout::left_to_right(egui::Align::Center), |ui| {
+                                                ui.add_sized([label_width, 20.0], egui::Label::new("COMMAND:"));
+                                                changed |= ui.color_edit_button_srgba(&mut colors.command).changed();
+                                            });
+                                        });
                                     });
                                 });
                             });
-                            
-                        // Apply changes if needed
-                        if changed {
-                            // Update shared colors
-                            colors_dynamic.set(colors.clone());
-                            
-                            // Save colors to file with correct path for gerber_viewer
-                            Self::save_colors_for_gerber_viewer(&colors);
-                        }
                         
                         ui.add_space(8.0);
                         
-                        // Add New Custom Type Section
+                        // JSON Colors Section
                         egui::Frame::group(ui.style())
                             .fill(ui.style().visuals.window_fill)
                             .show(ui, |ui| {
-                                ui.heading("Add New Custom Log Type");
+                                ui.heading("JSON Colors");
                                 ui.add_space(4.0);
                                 
-                                // Static storage for the new custom type name
+                                // Two-column layout for JSON syntax colors
+                                ui.columns(2, |columns| {
+                                    let label_width = 70.0;
+                                    
+                                    // Left column
+                                    columns[0].group(|ui| {
+                                        ui.horizontal(|ui| {
+                                            ui.with_layout(egui::Layout::left_to_right(egui::Align::Center), |ui| {
+                                                ui.add_sized([label_width, 20.0], egui::Label::new("KEY:"));
+                                                changed |= ui.color_edit_button_srgba(&mut colors.json_key).changed();
+                                            });
+                                        });
+                                        
+                                        ui.horizontal(|ui| {
+                                            ui.with_layout(egui::Layout::left_to_right(egui::Align::Center), |ui| {
+                                                ui.add_sized([label_width, 20.0], egui::Label::new("STRING:"));
+                                                changed |= ui.color_edit_button_srgba(&mut colors.json_string).changed();
+                                            });
+                                        });
+                                    });
+                                    
+                                    // Right column
+                                    columns[1].group(|ui| {
+                                        ui.horizontal(|ui| {
+                                            ui.with_layout(egui::Layout::left_to_right(egui::Align::Center), |ui| {
+                                                ui.add_sized([label_width, 20.0], egui::Label::new("NUMBER:"));
+                                                changed |= ui.color_edit_button_srgba(&mut colors.json_number).changed();
+                                            });
+                                        });
+                                        
+                                        ui.horizontal(|ui| {
+                                            ui.with_layout(egui::Layout::left_to_right(egui::Align::Center), |ui| {
+                                                ui.add_sized([label_width, 20.0], egui::Label::new("LITERAL:"));
+                                                changed |= ui.color_edit_button_srgba(&mut colors.json_literal).changed();
+                                            });
+                                        });
+                                    });
+                                });
+                            });
+                            
+                        // Apply changes if needed
+                        if changed {
+                            // Update shared colors
+                            colors_dynamic.set(colors.clone());
+                            
+                            // Save colors to file with correct path for gerber_viewer
+                            Self::save_colors_for_gerber_viewer(&colors);
+                        }
+                        
+                        ui.add_space(8.0);
+                        
+                        // Add New Custom Type Section
+                        egui::Frame::group(ui.style())
+                            .fill(ui.style().visuals.window_fill)
+                            .show(ui, |ui| {
+                                ui.heading("Add New Custom Log Type");
+                                ui.add_space(4.0);
+                                
+                                // Static storage for the new custom type name
                                 let mut new_custom_type = ui.ctx().memory_mut(|mem| {
-                                    mem.data.get_temp::<String>(egui::Id::new("new_custom_log_type"))
+                                    mem.data.get_temp::<String>(self.id("new_custom_log_type"))
                                         .unwrap_or_default()
                                 });
                                 
@@ -1347,7 +3204,7 @@ impl<'a> ReactiveEventLogger<'a> {
                                     
                                     if edit_response.changed() {
                                         ui.ctx().memory_mut(|mem| {
-                                            mem.data.insert_temp(egui::Id::new("new_custom_log_type"), new_custom_type.clone());
+                                            mem.data.insert_temp(self.id("new_custom_log_type"), new_custom_type.clone());
                                         });
                                     }
                                     
@@ -1375,7 +3232,7 @@ impl<'a> ReactiveEventLogger<'a> {
                                             // Clear the input
                                             new_custom_type.clear();
                                             ui.ctx().memory_mut(|mem| {
-                                                mem.data.insert_temp(egui::Id::new("new_custom_log_type"), String::new());
+                                                mem.data.insert_temp(self.id("new_custom_log_type"), String::new());
                                             });
                                         }
                                     }
@@ -1397,7 +3254,7 @@ impl<'a> ReactiveEventLogger<'a> {
                                     // Close button
                                     if ui.button("Close").clicked() {
                                         ui.ctx().memory_mut(|mem| {
-                                            mem.data.remove::<bool>(egui::Id::new("show_logger_colors_modal"));
+                                            mem.data.remove::<bool>(self.id("show_logger_colors_modal"));
                                         });
                                     }
                                     
@@ -1433,8 +3290,224 @@ impl<'a> ReactiveEventLogger<'a> {
         }
     }
     
+    /// Title for a tab or window showing the logger, with badges of the
+    /// unread warnings and errors, e.g. "Logger ⚠3 ✖1".
+    ///
+    /// The title takes the error or warning color while there are unread
+    /// errors or warnings. Showing the panel marks the entries as read.
+    pub fn tab_title(&self, title: &str) -> egui::WidgetText {
+        let Some(arc) = ReactiveWidgetRef::from_dynamic(self.state).weak_ref.upgrade() else {
+            return title.into();
+        };
+        let unread = arc.lock().unwrap().unread_counts();
+        
+        let mut text = title.to_string();
+        if unread.warnings > 0 {
+            text.push_str(&format!(" ⚠{}", format_count(unread.warnings as u64)));
+        }
+        if unread.errors > 0 {
+            text.push_str(&format!(" ✖{}", format_count(unread.errors as u64)));
+        }
+        
+        let colors = self.colors.map(|colors| colors.get()).unwrap_or_default();
+        let text = egui::RichText::new(text);
+        if unread.errors > 0 {
+            text.color(colors.error_level).into()
+        } else if unread.warnings > 0 {
+            text.color(colors.warning_level).into()
+        } else {
+            text.into()
+        }
+    }
+    
+    /// Window of a toast in the bottom right corner. Toasts are stacked
+    /// upwards by `slot`: the save result toasts first, the alerts above.
+    fn toast_window(&self, title: impl Into<egui::WidgetText>, id: egui::Id, slot: usize) -> egui::Window<'static> {
+        egui::Window::new(title)
+            .id(id)
+            .collapsible(false)
+            .resizable(false)
+            .anchor(egui::Align2::RIGHT_BOTTOM, [-10.0, -10.0 - slot as f32 * TOAST_SPACING])
+            .fixed_size([300.0, 80.0])
+    }
+    
+    /// Number of save result toasts currently shown
+    fn save_toast_count(&self, ctx: &egui::Context) -> usize {
+        ctx.data(|data| {
+            ["save_logs_success", "save_logs_error"]
+                .into_iter()
+                .filter(|name| data.get_temp::<String>(self.id(name)).is_some())
+                .count()
+        })
+    }
+    
+    /// Show the alerts raised by the alert rules as toasts.
+    ///
+    /// Call this once per frame from the application, also while the
+    /// logger panel is hidden, so alerts don't go unnoticed. Returns the
+    /// sequence number of the entry the user chose to show, so the
+    /// application can bring the logger panel to the front.
+    pub fn show_alert_toasts(&self, ctx: &egui::Context) -> Option<u64> {
+        let arc = ReactiveWidgetRef::from_dynamic(self.state).weak_ref.upgrade()?;
+        
+        // Close toasts that have been open long enough, and take the newest ones
+        let toasts: Vec<Alert> = {
+            let mut state = arc.lock().unwrap();
+            let now = chrono::Local::now();
+            for alert in state.alerts.raised.iter_mut().filter(|alert| alert.toast) {
+                if (now - alert.raised_at).num_seconds() >= ALERT_TOAST_SECONDS {
+                    alert.toast = false;
+                }
+            }
+            state.alerts.raised
+                .iter()
+                .rev()
+                .filter(|alert| alert.toast)
+                .take(MAX_ALERT_TOASTS)
+                .cloned()
+                .collect()
+        };
+        
+        let save_toasts = self.save_toast_count(ctx);
+        let mut dismissed = Vec::new();
+        let mut jump_to = None;
+        for (index, alert) in toasts.iter().enumerate() {
+            let icon = match alert.level.as_str() {
+                "ERROR" => "❌",
+                "WARNING" => "⚠",
+                _ => "🔔",
+            };
+            
+            // Stack the toasts above the save toasts
+            let toast = self.toast_window(
+                format!("{} {}", icon, alert.rule),
+                self.id("alert_toast").with(alert.id),
+                save_toasts + index,
+            );
+            
+            toast.show(ctx, |ui| {
+                ui.vertical_centered(|ui| {
+                    ui.add_space(5.0);
+                    ui.label(shorten_text(&alert.message, 120));
+                    ui.add_space(5.0);
+                    
+                    ui.horizontal(|ui| {
+                        if ui.button("Show").clicked() {
+                            jump_to = Some(alert.sequence);
+                            dismissed.push(alert.id);
+                        }
+                        if ui.button("Close").clicked() {
+                            dismissed.push(alert.id);
+                        }
+                    });
+                });
+            });
+        }
+        
+        // Close the toasts on time even if nothing else happens
+        if !toasts.is_empty() {
+            ctx.request_repaint_after(std::time::Duration::from_secs(1));
+        }
+        
+        if !dismissed.is_empty() {
+            let mut state = arc.lock().unwrap();
+            for id in dismissed {
+                state.alerts.dismiss(id);
+            }
+        }
+        if let Some(sequence) = jump_to {
+            self.jump_to_entry(ctx, sequence);
+        }
+        jump_to
+    }
+    
+    /// Select an entry and scroll the table to it, showing its channel
+    fn jump_to_entry(&self, ctx: &egui::Context, sequence: u64) {
+        if let Some(arc) = ReactiveWidgetRef::from_dynamic(self.state).weak_ref.upgrade() {
+            let mut state = arc.lock().unwrap();
+            let channel = state.find_log(sequence).map(|log| log.channel.clone());
+            if let Some(channel) = channel
+                && state.active_channel.is_some()
+                && state.active_channel != channel {
+                state.set_active_channel(None);
+            }
+            state.select_log(sequence, egui::Modifiers::NONE, &[sequence]);
+            state.pause_follow();
+        }
+        ctx.data_mut(|data| data.insert_temp(self.id("logger_scroll_to_entry"), sequence));
+    }
+    
+    /// Display a built-in button of the toolbar
+    fn show_toolbar_button(&self, ui: &mut egui::Ui, button: ToolbarButton, state_value: &ReactiveEventLoggerState) {
+        let state_ref = ReactiveWidgetRef::from_dynamic(self.state);
+        
+        match button {
+            ToolbarButton::PopOut => {
+                // Pop the logger out into its own window, or dock it back
+                let (popout_text, popout_hover) = if state_value.popped_out {
+                    ("⧉ Dock back", "Move the logger back into the panel")
+                } else {
+                    ("⧉ Pop out", "Move the logger into a separate window")
+                };
+                if ui.button(popout_text).on_hover_text(popout_hover).clicked()
+                    && let Some(arc) = state_ref.weak_ref.upgrade() {
+                    let mut state = arc.lock().unwrap();
+                    state.popped_out = !state.popped_out;
+                }
+            }
+            ToolbarButton::Clear => {
+                // Clear logs if button clicked
+                if ui.button("Clear Logs").clicked()
+                    && let Some(arc) = state_ref.weak_ref.upgrade() {
+                    arc.lock().unwrap().clear_logs();
+                }
+            }
+            ToolbarButton::Save => {
+                if ui.button("💾 Save Logs").clicked() {
+                    // Set a flag to open the save dialog
+                    ui.ctx().memory_mut(|mem| {
+                        mem.data.insert_temp(self.id("show_save_logs_dialog"), true);
+                    });
+                }
+            }
+            ToolbarButton::Colors => {
+                if ui.button("🎨 Logger Colors").clicked() {
+                    // Set a flag to open the color dialog
+                    ui.ctx().memory_mut(|mem| {
+                        mem.data.insert_temp(self.id("show_logger_colors_modal"), true);
+                    });
+                }
+            }
+            ToolbarButton::Filters => {
+                let filter_button_text = if is_any_filter_active(&state_value.filter) {
+                    "🔍 Filters (Active)"
+                } else {
+                    "🔍 Filters"
+                };
+                
+                if ui.button(filter_button_text).clicked() {
+                    // Signal to open the filter modal
+                    ui.ctx().memory_mut(|mem| {
+                        mem.data.insert_temp(self.id("show_logger_filter_modal"), true);
+                    });
+                }
+            }
+            ToolbarButton::Presets => {
+                // Filter presets dropdown
+                self.show_filter_preset_menu(ui, state_value);
+            }
+        }
+    }
+    
     /// Displays the event log content with columns based on state
-    fn show_event_log_content(&self, ui: &mut egui::Ui, state: &ReactiveEventLoggerState) {
+    fn show_event_log_content(
+        &self,
+        ui: &mut egui::Ui,
+        state: &ReactiveEventLoggerState,
+        pane: usize,
+        search: Option<&(SearchMatcher, Option<u64>)>,
+        scroll_target: Option<u64>,
+    ) {
         // Get column visibility settings
         let show_timestamps = state.show_timestamps;
         let show_log_level = state.show_log_level;
@@ -1446,37 +3519,82 @@ impl<'a> ReactiveEventLogger<'a> {
             return;
         }
         
-        // Fixed widths for timestamp and log level columns
-        const TIMESTAMP_WIDTH: f32 = 190.0;
-        const LEVEL_WIDTH: f32 = 100.0;
+        // Messages parsed as JSON in earlier frames
+        let json_cache = self.json_cache(ui.ctx(), state);
+        
+        // Column widths and row height from the config
+        let timestamp_width = state.config.columns.timestamp_width;
+        let level_width = state.config.columns.level_width;
+        let row_height = state.config.row_height;
+        let row_font = match &state.config.font {
+            Some(font) => font.font_id(),
+            None => egui::TextStyle::Monospace.resolve(ui.style()),
+        };
+        const GUTTER_WIDTH: f32 = 18.0;
+        const GROUP_INDENT: f32 = 16.0;
+        
+        // The second pane of a split view has its own filter
+        let filter = if pane == 0 { &state.filter } else { &state.split_filter };
         
         // If we have custom colors, use rich text with the layout
         if let Some(colors_dynamic) = self.colors {
             // Get a copy of the colors from the Dynamic
             let colors = colors_dynamic.get();
             
+            // Rows in display order, and what the user did with them this frame
+            let mut visible_order: Vec<u64> = Vec::new();
+            let mut row_action: Option<RowAction> = None;
+            
+            // Time the other pane of a split view was scrolled to, and the
+            // time of the top row of this pane
+            let sync_id = self.id("logger_split_sync");
+            let sync_to = if state.split_view != SplitView::Off && !state.follow {
+                ui.ctx().data(|data| data.get_temp::<(usize, chrono::DateTime<chrono::Local>)>(sync_id))
+                    .filter(|(source, _)| *source != pane)
+                    .map(|(_, time)| time)
+            } else {
+                None
+            };
+            let mut synced = false;
+            let mut top_time: Option<chrono::DateTime<chrono::Local>> = None;
+            
+            // While following, keep the live edge in view: the top for
+            // newest-first rows, the bottom for chronological rows
+            let mut scroll_area = egui::ScrollArea::vertical()
+                .id_salt(("logger_table", pane))
+                .auto_shrink([false, false]);
+            if state.follow && scroll_target.is_none() {
+                scroll_area = scroll_area.vertical_scroll_offset(match state.row_order {
+                    RowOrder::NewestFirst => 0.0,
+                    RowOrder::OldestFirst => f32::INFINITY,
+                });
+            }
+            
             // Create a scrollable area for log content
-            egui::ScrollArea::vertical()
-                .auto_shrink([false, false])
-                .stick_to_bottom(true)
+            let scroll_output = scroll_area
                 .show(ui, |ui| {
+                    let clip_top = ui.clip_rect().top();
+                    
                     // Create a table with headers
-                    egui::Grid::new("logger_grid")
-                        .num_columns(if show_timestamps { 1 } else { 0 } + 
+                    egui::Grid::new(("logger_grid", pane))
+                        .num_columns(1 +
+                                     if show_timestamps { 1 } else { 0 } + 
                                      if show_log_level { 1 } else { 0 } + 
                                      if show_messages { 1 } else { 0 })
-                        .striped(true)
+                        .striped(state.config.striped)
                         .spacing([10.0, 4.0])
                         .min_col_width(0.0) // Allow us to fully control column widths
                         .show(ui, |ui| {
-                            // Header row
+                            // Header row, starting with the bookmark gutter
+                            ui.add_sized([GUTTER_WIDTH, row_height], egui::Label::new(""));
+                            
                             if show_timestamps {
-                                ui.add_sized([TIMESTAMP_WIDTH, 20.0], 
+                                ui.add_sized([timestamp_width, row_height], 
                                     egui::Label::new(egui::RichText::new("Timestamp").strong().size(14.0)));
                             }
                             
                             if show_log_level {
-                                ui.add_sized([LEVEL_WIDTH, 20.0],
+                                ui.add_sized([level_width, row_height],
                                     egui::Label::new(egui::RichText::new("Level").strong().size(14.0)));
                             }
                             
@@ -1498,32 +3616,58 @@ impl<'a> ReactiveEventLogger<'a> {
                                            (if show_log_level { 1 } else { 0 }) + 
                                            (if show_messages { 1 } else { 0 });
                             
+                            ui.label("");
                             for _ in 0..col_count {
                                 ui.label("―――――――――――――");
                             }
                             ui.end_row();
                             
-                            // Process logs in reverse order (newest first)
-                            for log in state.logs.iter().rev() {
-                                // Apply filter - skip logs that don't match the filter criteria
-                                if !state.filter.should_display(log) {
-                                    continue;
+                            // Process the logs passing the filter in display order
+                            for log in state.displayed_logs_with(filter) {
+                                visible_order.push(log.sequence);
+                                let selected = state.selected.contains(&log.sequence);
+                                let highlight = search.map(|(matcher, current)| {
+                                    (matcher, *current == Some(log.sequence))
+                                });
+                                let mut row_response: Option<egui::Response> = None;
+                                
+                                // Gutter with the bookmark and note markers; clicking toggles the bookmark
+                                let marker = match (log.bookmarked, &log.note) {
+                                    (true, _) => "🔖",
+                                    (false, Some(_)) => "📝",
+                                    (false, None) => "",
+                                };
+                                let gutter = selectable_cell(ui, selected, |ui| {
+                                    ui.add_sized([GUTTER_WIDTH, row_height], row_label(marker))
+                                });
+                                let gutter = match &log.note {
+                                    Some(note) => gutter.on_hover_text(note),
+                                    None => gutter.on_hover_text("Click to toggle the bookmark"),
+                                };
+                                if gutter.clicked() {
+                                    row_action = Some(RowAction::ToggleBookmark(log.sequence));
                                 }
                                 
                                 if show_timestamps {
                                     let timestamp_text = egui::RichText::new(&log.timestamp.value.value)
                                         .color(colors.timestamp)
-                                        .monospace();
-                                    ui.add_sized([TIMESTAMP_WIDTH, 20.0], egui::Label::new(timestamp_text));
+                                        .font(row_font.clone());
+                                    let response = selectable_cell(ui, selected, |ui| {
+                                        ui.add_sized([timestamp_width, row_height], row_label(timestamp_text))
+                                    });
+                                    row_response = Some(response);
                                 }
                                 
                                 if show_log_level {
                                     let (level_text, level_color) = get_log_level_text_and_color(log, &colors);
-                                    ui.add_sized([LEVEL_WIDTH, 20.0], 
-                                        egui::Label::new(
-                                            egui::RichText::new(level_text)
-                                            .color(level_color)
-                                            .monospace()));
+                                    let response = selectable_cell(ui, selected, |ui| {
+                                        ui.add_sized([level_width, row_height], 
+                                            row_label(
+                                                egui::RichText::new(level_text)
+                                                .color(level_color)
+                                                .font(row_font.clone())))
+                                    });
+                                    row_response = Some(union_response(row_response, response));
                                 }
                                 
                                 if show_messages {
@@ -1536,122 +3680,990 @@ impl<'a> ReactiveEventLogger<'a> {
                                     };
                                     
                                     // Determine color based on log level first, then message content
-                                    let message_color = if !log.log_level.info.value.is_empty() {
-                                        // Check if it's a custom type
-                                        if log.log_level.info.value.starts_with("CUSTOM:") {
-                                            let identifier = log.log_level.info.value.strip_prefix("CUSTOM:").unwrap_or("");
-                                            colors.get_custom_color_message(identifier)
-                                        } else {
-                                            colors.info_message
-                                        }
-                                    } else if !log.log_level.warning.value.is_empty() {
-                                        colors.warning_message
-                                    } else if !log.log_level.error.value.is_empty() {
-                                        colors.error_message
-                                    } else if !log.log_level.debug.value.is_empty() {
-                                        colors.debug_message
-                                    } else {
-                                        // Fallback to content-based detection
-                                        get_message_color(&formatted_message, &colors)
-                                    };
+                                    let message_color = get_log_message_color(log, &formatted_message, &colors);
                                     
                                     // Calculate available width to make the message column stretch
                                     let available_width = ui.available_width().max(300.0);
                                     
                                     // Create a label that fills the available width
-                                    ui.scope(|ui| {
-                                        ui.set_min_width(available_width);
-                                        ui.add(egui::Label::new(
-                                            egui::RichText::new(formatted_message)
-                                                .color(message_color)
-                                                .monospace()));
+                                    let response = selectable_cell(ui, selected, |ui| {
+                                        ui.scope(|ui| {
+                                            ui.set_min_width(available_width);
+                                            
+                                            // Indent entries nested in operation groups
+                                            if !log.groups.is_empty() {
+                                                ui.add_space(GROUP_INDENT * log.groups.len() as f32);
+                                            }
+                                            
+                                            // Channel of the entry in the merged view
+                                            if state.active_channel.is_none()
+                                                && let Some(channel) = &log.channel {
+                                                ui.label(egui::RichText::new(format!("[{}]", channel)).font(row_font.clone()).weak());
+                                            }
+                                            
+                                            // Collapse toggle of a group header
+                                            let collapsed = state.collapsed_groups.contains(&log.sequence);
+                                            if log.group.is_some()
+                                                && ui.add(egui::Button::new(if collapsed { "▶" } else { "▼" }).small().frame(false))
+                                                    .on_hover_text(if collapsed { "Expand group" } else { "Collapse group" })
+                                                    .clicked() {
+                                                row_action = Some(RowAction::ToggleGroup(log.sequence));
+                                            }
+                                            
+                                            let json = state.pretty_json
+                                                .then(|| json_cache.get(log.sequence, &formatted_message))
+                                                .flatten();
+                                            let formatted_message = if state.strip_ansi && ansi::contains_ansi(&formatted_message) {
+                                                ansi::strip_ansi(&formatted_message)
+                                            } else {
+                                                formatted_message
+                                            };
+                                            let format = egui::TextFormat::simple(row_font.clone(), message_color);
+                                            let response = message_cell(ui, log, formatted_message, json, format, &colors, highlight);
+                                            
+                                            // Duration of a closed group and the number of hidden entries
+                                            if let Some(group) = &log.group {
+                                                let mut summary = match group.duration {
+                                                    Some(duration) => format!("({})", format_duration(duration)),
+                                                    None => "(running)".to_string(),
+                                                };
+                                                if collapsed {
+                                                    let hidden = state.logs.iter().filter(|entry| entry.groups.contains(&log.sequence)).count();
+                                                    summary.push_str(&format!(" {} entries hidden", format_count(hidden as u64)));
+                                                }
+                                                ui.label(egui::RichText::new(summary).weak());
+                                            }
+                                            
+                                            response
+                                        }).inner
                                     });
+                                    row_response = Some(union_response(row_response, response));
                                 }
                                 
                                 ui.end_row();
+                                
+                                if scroll_target == Some(log.sequence)
+                                    && let Some(response) = &row_response {
+                                    response.scroll_to_me(Some(egui::Align::Center));
+                                }
+                                
+                                if let Some(response) = &row_response {
+                                    // Remember the time of the first visible row
+                                    if top_time.is_none() && response.rect.bottom() > clip_top {
+                                        top_time = Some(log.created_at);
+                                    }
+                                    
+                                    // Line up with the other pane at the first row at or past its time
+                                    let reached = sync_to.is_some_and(|time| match state.row_order {
+                                        RowOrder::NewestFirst => log.created_at <= time,
+                                        RowOrder::OldestFirst => log.created_at >= time,
+                                    });
+                                    if reached && !synced {
+                                        response.scroll_to_me(Some(egui::Align::Min));
+                                        synced = true;
+                                    }
+                                }
+                                
+                                if let Some(response) = row_response
+                                    && let Some(action) = self.row_interaction(&response, log, state) {
+                                    row_action = Some(action);
+                                }
                             }
                         });
                 });
             
+            // Publish the time of the top row when the user scrolls this pane,
+            // and forget a time the pane has been lined up with
+            if state.split_view != SplitView::Off {
+                let offset_id = self.id("logger_pane_offset").with(pane);
+                let offset = scroll_output.state.offset.y;
+                let previous = ui.ctx().data_mut(|data| data.get_temp::<f32>(offset_id));
+                ui.ctx().data_mut(|data| data.insert_temp(offset_id, offset));
+                
+                let scrolled = previous.is_some_and(|previous| (previous - offset).abs() > 0.5);
+                if scrolled
+                    && ui.rect_contains_pointer(scroll_output.inner_rect)
+                    && let Some(time) = top_time {
+                    ui.ctx().data_mut(|data| data.insert_temp(sync_id, (pane, time)));
+                } else if sync_to.is_some() {
+                    ui.ctx().data_mut(|data| data.remove::<(usize, chrono::DateTime<chrono::Local>)>(sync_id));
+                }
+            }
+            
+            // Pause following once the user scrolls away from the live edge
+            let max_offset = (scroll_output.content_size.y - scroll_output.inner_rect.height()).max(0.0);
+            let at_live_edge = match state.row_order {
+                RowOrder::NewestFirst => scroll_output.state.offset.y <= 0.5,
+                RowOrder::OldestFirst => scroll_output.state.offset.y >= max_offset - 0.5,
+            };
+            if state.follow
+                && !at_live_edge
+                && let Some(arc) = ReactiveWidgetRef::from_dynamic(self.state).weak_ref.upgrade() {
+                arc.lock().unwrap().pause_follow();
+            }
+            
+            // "▼ 142 new entries" pill at the live edge to resume following
+            // (not while frozen, since the new entries are hidden)
+            let new_entries = state.new_entries_since_pause();
+            if !state.follow && new_entries > 0 && state.frozen_at.is_none() {
+                let rect = scroll_output.inner_rect;
+                let (arrow, center) = match state.row_order {
+                    RowOrder::NewestFirst => ("▲", rect.center_top() + egui::vec2(0.0, 40.0)),
+                    RowOrder::OldestFirst => ("▼", rect.center_bottom() - egui::vec2(0.0, 20.0)),
+                };
+                let pill = egui::Button::new(format!("{} {} new entries", arrow, format_count(new_entries as u64)))
+                    .corner_radius(12.0)
+                    .fill(ui.visuals().selection.bg_fill);
+                if ui.put(egui::Rect::from_center_size(center, egui::vec2(180.0, 24.0)), pill)
+                    .on_hover_text("Resume following new entries")
+                    .clicked()
+                    && let Some(arc) = ReactiveWidgetRef::from_dynamic(self.state).weak_ref.upgrade() {
+                    arc.lock().unwrap().resume_follow();
+                }
+            }
+            
+            // Apply whatever the user did with the rows this frame
+            if let Some(action) = row_action {
+                self.apply_row_action(ui.ctx(), action, &visible_order);
+            }
+            
             return;
         }
         
         // Fallback to plain text if colors are not available
-        self.show_plain_text_logs(ui, state);
+        self.show_plain_text_logs(ui, state, filter);
     }
     
-    /// Fallback to plain text display when colors are not available
-    fn show_plain_text_logs(&self, ui: &mut egui::Ui, state: &ReactiveEventLoggerState) {
-        // Get column visibility settings
-        let show_timestamps = state.show_timestamps;
-        let show_log_level = state.show_log_level;
-        let show_messages = state.show_messages;
+    /// Display one pane of a split view: a header with the pane's filter,
+    /// followed by its table
+    fn show_split_pane(
+        &self,
+        ui: &mut egui::Ui,
+        state: &ReactiveEventLoggerState,
+        pane: usize,
+        search: Option<&(SearchMatcher, Option<u64>)>,
+        scroll_target: Option<u64>,
+    ) {
+        ui.horizontal(|ui| {
+            if pane == 0 {
+                ui.label(egui::RichText::new("Main filter").strong());
+                ui.label(egui::RichText::new("(Filters button)").weak());
+                return;
+            }
+            
+            ui.label(egui::RichText::new("Second pane").strong());
+            let mut filter = state.split_filter.clone();
+            
+            // Start from a saved preset
+            egui::ComboBox::from_id_salt("logger_split_preset")
+                .selected_text("Preset")
+                .show_ui(ui, |ui| {
+                    for preset in &state.filter_presets.presets {
+                        if ui.selectable_label(false, &preset.name).clicked() {
+                            filter = preset.filter.clone();
+                        }
+                    }
+                });
+            
+            // Restrict to one custom type, e.g. "network" against "database"
+            let mut custom_types: Vec<&str> = state.logs
+                .iter()
+                .filter_map(|log| log.level().strip_prefix("CUSTOM:"))
+                .collect();
+            custom_types.sort_unstable();
+            custom_types.dedup();
+            egui::ComboBox::from_id_salt("logger_split_custom_type")
+                .selected_text(filter.only_custom_type.as_deref().unwrap_or("Any type"))
+                .show_ui(ui, |ui| {
+                    ui.selectable_value(&mut filter.only_custom_type, None, "Any type");
+                    for custom_type in custom_types {
+                        ui.selectable_value(&mut filter.only_custom_type, Some(custom_type.to_string()), custom_type);
+                    }
+                });
+            
+            ui.add(egui::TextEdit::singleline(&mut filter.text_filter)
+                .hint_text("Contains…")
+                .desired_width(120.0));
+            
+            if filter != state.split_filter
+                && let Some(arc) = ReactiveWidgetRef::from_dynamic(self.state).weak_ref.upgrade() {
+                arc.lock().unwrap().split_filter = filter;
+            }
+        });
         
-        if !show_timestamps && !show_log_level && !show_messages {
-            // Nothing to show
-            ui.label("No columns selected");
-            return;
+        self.show_event_log_content(ui, state, pane, search, scroll_target);
+    }
+    
+    /// Display the channel tab strip with unread and error badges
+    fn show_channel_tabs(&self, ui: &mut egui::Ui, state: &ReactiveEventLoggerState) {
+        let state_ref = ReactiveWidgetRef::from_dynamic(self.state);
+        let mut clicked = None;
+        
+        ui.horizontal_wrapped(|ui| {
+            if ui.selectable_label(state.active_channel.is_none(), "All")
+                .on_hover_text("All channels, merged by timestamp")
+                .clicked()
+                && state.active_channel.is_some() {
+                clicked = Some(None);
+            }
+            
+            for channel in &state.channels {
+                let active = state.active_channel.as_ref() == Some(channel);
+                let (unread, errors) = if active { (0, 0) } else { state.channel_unread(channel) };
+                
+                let mut text = channel.clone();
+                if unread > 0 {
+                    text.push_str(&format!(" ●{}", format_count(unread as u64)));
+                }
+                if errors > 0 {
+                    text.push_str(&format!(" ✖{}", format_count(errors as u64)));
+                }
+                let text = if errors > 0 {
+                    egui::RichText::new(text).color(ui.visuals().error_fg_color)
+                } else {
+                    egui::RichText::new(text)
+                };
+                
+                if ui.selectable_label(active, text)
+                    .on_hover_text(format!("{} unread, {} unread errors", unread, errors))
+                    .clicked()
+                    && !active {
+                    clicked = Some(Some(channel.clone()));
+                }
+            }
+        });
+        
+        // Switch tabs, and keep the shown channel read while new entries arrive.
+        // The state is only locked if one of the two actually changes it.
+        let active_unread = state.active_channel
+            .as_deref()
+            .is_some_and(|channel| state.channel_unread(channel).0 > 0);
+        if (clicked.is_some() || active_unread)
+            && let Some(arc) = state_ref.weak_ref.upgrade() {
+            let mut state = arc.lock().unwrap();
+            match clicked {
+                Some(channel) => state.set_active_channel(channel.as_deref()),
+                None => {
+                    if let Some(channel) = state.active_channel.clone() {
+                        state.mark_channel_read(&channel);
+                    }
+                }
+            }
         }
+    }
+    
+    /// Display the search bar and handle its keyboard shortcuts.
+    ///
+    /// Ctrl+F opens the bar, Escape closes it, F3 / Shift+F3 (or Enter /
+    /// Shift+Enter in the search field) move to the next / previous hit.
+    /// Returns the active matcher and the current hit for highlighting.
+    fn show_search_bar(&self, ui: &mut egui::Ui, state: &ReactiveEventLoggerState, shortcuts: bool) -> Option<(SearchMatcher, Option<u64>)> {
+        let mut search = state.search.clone();
+        let field_id = self.id("logger_search_field");
+        let scroll_id = self.id("logger_scroll_to_entry");
         
-        // Calculate available height to fill the panel
-        let available_height = ui.available_height();
+        if shortcuts && ui.input_mut(|i| i.consume_key(egui::Modifiers::COMMAND, egui::Key::F)) {
+            search.open = true;
+            ui.memory_mut(|mem| mem.request_focus(field_id));
+        }
         
-        let mut log_text = String::new();
+        // Step direction requested this frame: Some(true) = next, Some(false) = previous
+        let mut step = if !search.open || !shortcuts {
+            None
+        } else if ui.input_mut(|i| i.consume_key(egui::Modifiers::SHIFT, egui::Key::F3)) {
+            Some(false)
+        } else if ui.input_mut(|i| i.consume_key(egui::Modifiers::NONE, egui::Key::F3)) {
+            Some(true)
+        } else {
+            None
+        };
         
-        // Process logs in reverse order (newest first)
-        for log in state.logs.iter().rev() {
-            // Apply filter - skip logs that don't match the filter criteria
-            if !state.filter.should_display(log) {
-                continue;
-            }
+        let mut result = None;
+        if search.open {
+            let mut results = self.search_results(ui.ctx(), state, &search);
             
-            if show_timestamps {
-                log_text.push_str(&format!("{} ", log.timestamp.value.value));
+            ui.horizontal(|ui| {
+                ui.label("🔎");
+                let response = ui.add(egui::TextEdit::singleline(&mut search.query)
+                    .id(field_id)
+                    .hint_text("Search (F3 / Shift+F3 to navigate)")
+                    .desired_width(260.0));
+                
+                if response.lost_focus() && ui.input(|i| i.key_pressed(egui::Key::Escape)) {
+                    search.open = false;
+                }
+                if response.lost_focus() && ui.input(|i| i.key_pressed(egui::Key::Enter)) {
+                    step = Some(!ui.input(|i| i.modifiers.shift));
+                    response.request_focus();
+                }
+                
+                ui.toggle_value(&mut search.case_sensitive, "Aa").on_hover_text("Match case");
+                ui.toggle_value(&mut search.regex, ".*").on_hover_text("Regular expression");
+                
+                match &results.matcher {
+                    Err(err) => {
+                        ui.label(egui::RichText::new("Invalid regex").color(egui::Color32::RED))
+                            .on_hover_text(err);
+                    }
+                    Ok(None) => {}
+                    Ok(Some(_)) => {
+                        let position = search.current
+                            .and_then(|current| results.hits.iter().position(|hit| *hit == current))
+                            .map_or(0, |position| position + 1);
+                        ui.label(format!("{} of {}", position, results.hits.len()));
+                    }
+                }
+                
+                if ui.small_button("⬆").on_hover_text("Previous match (Shift+F3)").clicked() {
+                    step = Some(false);
+                }
+                if ui.small_button("⬇").on_hover_text("Next match (F3)").clicked() {
+                    step = Some(true);
+                }
+                if ui.small_button("✖").on_hover_text("Close search (Escape)").clicked() {
+                    search.open = false;
+                }
+            });
+            
+            // A new query jumps to its first hit
+            let query_changed = search.query != state.search.query
+                || search.regex != state.search.regex
+                || search.case_sensitive != state.search.case_sensitive;
+            if query_changed {
+                search.current = None;
+                step = Some(true);
+                results = self.search_results(ui.ctx(), state, &search);
             }
             
-            if show_log_level {
-                // Find the non-empty log level
-                if !log.log_level.info.value.is_empty() {
-                    log_text.push_str(&format!("[{}] ", log.log_level.info.value));
-                } else if !log.log_level.debug.value.is_empty() {
-                    log_text.push_str(&format!("[{}] ", log.log_level.debug.value));
-                } else if !log.log_level.warning.value.is_empty() {
-                    log_text.push_str(&format!("[{}] ", log.log_level.warning.value));
-                } else if !log.log_level.error.value.is_empty() {
-                    log_text.push_str(&format!("[{}] ", log.log_level.error.value));
+            if let Some(forward) = step {
+                search.current = step_hit(&results.hits, search.current, forward);
+                if let Some(current) = search.current {
+                    ui.ctx().data_mut(|data| data.insert_temp(scroll_id, current));
                 }
             }
             
-            if show_messages {
-                log_text.push_str(&log.log_message.content.value);
+            if search.open
+                && let Ok(Some(matcher)) = results.matcher {
+                result = Some((matcher, search.current));
             }
-            
-            log_text.push('\n');
         }
         
-        // Create a scrollable area for the plain text content
-        egui::ScrollArea::vertical()
-            .auto_shrink([false, false])
-            .stick_to_bottom(true)
-            .show(ui, |ui| {
-                // Show the logs in a monospace, non-interactive text editor that fills the space
-                egui::TextEdit::multiline(&mut log_text)
-                    .font(egui::TextStyle::Monospace)
-                    .desired_width(f32::INFINITY)
-                    .min_size(egui::vec2(ui.available_width(), available_height))
-                    .interactive(false)
-                    .show(ui);
-            });
+        if search != state.search
+            && let Some(arc) = ReactiveWidgetRef::from_dynamic(self.state).weak_ref.upgrade() {
+            arc.lock().unwrap().search = search;
+        }
+        
+        result
     }
-}
-
-// Helper function to get log level text and color
-pub fn get_log_level_text_and_color(log: &LoggerPayload, colors: &LogColors) -> (String, egui::Color32) {
-    if !log.log_level.info.value.is_empty() {
+    
+    /// Cache of the messages parsed as JSON, kept across frames
+    fn json_cache(&self, ctx: &egui::Context, state: &ReactiveEventLoggerState) -> JsonCache {
+        let cache = ctx.data_mut(|data| data.get_temp_mut_or_default::<JsonCache>(self.id("logger_json_cache")).clone());
+        let first_sequence = state.logs.first().map_or(state.next_sequence, |log| log.sequence);
+        cache.sync(first_sequence, state.logs.len(), state.edits);
+        cache
+    }
+    
+    /// Compile the search and find its hits, or reuse the results of an
+    /// earlier frame if neither the search nor the displayed entries changed
+    fn search_results(&self, ctx: &egui::Context, state: &ReactiveEventLoggerState, search: &LogSearch) -> SearchCache {
+        let cache_id = self.id("logger_search_cache");
+        let key = state.search_key(search);
+        if let Some(cache) = ctx.data(|data| data.get_temp::<SearchCache>(cache_id))
+            && cache.key == key {
+            return cache;
+        }
+        
+        let matcher = search.matcher();
+        let hits = match &matcher {
+            Ok(Some(matcher)) => state.search_hits(matcher),
+            _ => Vec::new(),
+        };
+        let cache = SearchCache { key, matcher, hits: std::sync::Arc::new(hits) };
+        ctx.data_mut(|data| data.insert_temp(cache_id, cache.clone()));
+        cache
+    }
+    
+    /// Display the detail inspector for the selected entry
+    fn show_detail_pane(&self, ui: &mut egui::Ui, state: &ReactiveEventLoggerState) {
+        let Some(log) = state.focused_log() else {
+            return;
+        };
+        
+        match state.detail_pane {
+            DetailPane::Hidden => {}
+            DetailPane::Right => {
+                egui::SidePanel::right(self.id("logger_detail_pane"))
+                    .resizable(true)
+                    .default_width(320.0)
+                    .min_width(200.0)
+                    .show_inside(ui, |ui| self.show_log_details(ui, log, state));
+            }
+            DetailPane::Bottom => {
+                egui::TopBottomPanel::bottom(self.id("logger_detail_pane"))
+                    .resizable(true)
+                    .default_height(200.0)
+                    .min_height(100.0)
+                    .show_inside(ui, |ui| self.show_log_details(ui, log, state));
+            }
+        }
+    }
+    
+    /// Display the command line with history (up / down) and completion (Tab)
+    fn show_command_line(&self, ui: &mut egui::Ui, state: &ReactiveEventLoggerState) {
+        let field_id = self.id("logger_command_field");
+        let input_id = self.id("logger_command_input");
+        let history_id = self.id("logger_command_history_position");
+        let candidates_id = self.id("logger_command_candidates");
+        
+        egui::TopBottomPanel::bottom(self.id("logger_command_line")).show_inside(ui, |ui| {
+            let mut input = ui.ctx().data_mut(|data| data.get_temp::<String>(input_id)).unwrap_or_default();
+            let mut history_position = ui.ctx().data_mut(|data| data.get_temp::<usize>(history_id));
+            let mut candidates = ui.ctx().data_mut(|data| data.get_temp::<Vec<String>>(candidates_id)).unwrap_or_default();
+            let mut replaced = false;
+            
+            ui.horizontal(|ui| {
+                ui.label(egui::RichText::new(">").monospace().strong());
+                let response = ui.add(egui::TextEdit::singleline(&mut input)
+                    .id(field_id)
+                    .font(egui::TextStyle::Monospace)
+                    .hint_text("Type a command, e.g. help (Tab completes, ↑/↓ history)")
+                    .lock_focus(true)
+                    .desired_width(f32::INFINITY));
+                
+                if response.changed() {
+                    history_position = None;
+                    candidates.clear();
+                }
+                
+                if response.has_focus() {
+                    // Keep Tab and the vertical arrows in the field instead of moving the focus
+                    ui.memory_mut(|mem| mem.set_focus_lock_filter(field_id, egui::EventFilter {
+                        tab: true,
+                        horizontal_arrows: true,
+                        vertical_arrows: true,
+                        escape: false,
+                    }));
+                    
+                    let (tab, up, down) = ui.input_mut(|i| (
+                        i.consume_key(egui::Modifiers::NONE, egui::Key::Tab),
+                        i.consume_key(egui::Modifiers::NONE, egui::Key::ArrowUp),
+                        i.consume_key(egui::Modifiers::NONE, egui::Key::ArrowDown),
+                    ));
+                    
+                    if tab {
+                        (input, candidates) = state.commands.complete(&input);
+                        replaced = true;
+                    }
+                    
+                    // Walk the history from the newest command
+                    let history = &state.command_history;
+                    if up && !history.is_empty() {
+                        let position = history_position.map_or(history.len() - 1, |position| position.saturating_sub(1));
+                        history_position = Some(position);
+                        input = history[position].clone();
+                        replaced = true;
+                    }
+                    if down && let Some(position) = history_position {
+                        if position + 1 < history.len() {
+                            history_position = Some(position + 1);
+                            input = history[position + 1].clone();
+                        } else {
+                            history_position = None;
+                            input.clear();
+                        }
+                        replaced = true;
+                    }
+                }
+                
+                if response.lost_focus() && ui.input(|i| i.key_pressed(egui::Key::Enter)) {
+                    let ctx = ui.ctx().clone();
+                    self.update_state(|state| {
+                        state.run_command(&input);
+                        state.resume_follow();
+                        // Commands may change the filter, which the filter
+                        // modal reloads from memory
+                        state.filter.save_to_memory_with_id(&ctx, self.id_source);
+                    });
+                    input.clear();
+                    history_position = None;
+                    candidates.clear();
+                    response.request_focus();
+                }
+            });
+            
+            // Candidates of an ambiguous completion
+            if candidates.len() > 1 {
+                ui.label(egui::RichText::new(candidates.join("  ")).monospace().weak());
+            }
+            
+            // Put the cursor at the end of completed or recalled input
+            if replaced
+                && let Some(mut text_state) = egui::TextEdit::load_state(ui.ctx(), field_id) {
+                let end = egui::text::CCursor::new(input.chars().count());
+                text_state.cursor.set_char_range(Some(egui::text::CCursorRange::one(end)));
+                text_state.store(ui.ctx(), field_id);
+            }
+            
+            ui.ctx().data_mut(|data| {
+                data.insert_temp(input_id, input);
+                match history_position {
+                    Some(position) => data.insert_temp(history_id, position),
+                    None => data.remove::<usize>(history_id),
+                }
+                data.insert_temp(candidates_id, candidates);
+            });
+        });
+    }
+    
+    /// Display all information about a single log entry
+    fn show_log_details(&self, ui: &mut egui::Ui, log: &LoggerPayload, state: &ReactiveEventLoggerState) {
+        let colors = self.colors.map(|colors| colors.get()).unwrap_or_default();
+        let (level_text, level_color) = get_log_level_text_and_color(log, &colors);
+        let archive_id = self.id("logger_archive_text").with(log.sequence);
+        
+        ui.horizontal(|ui| {
+            ui.heading(format!("Entry #{}", log.sequence));
+            ui.with_layout(egui::Layout::right_to_left(egui::Align::Center), |ui| {
+                if ui.small_button("✖").on_hover_text("Close (clears the selection)").clicked()
+                    && let Some(arc) = ReactiveWidgetRef::from_dynamic(self.state).weak_ref.upgrade() {
+                    arc.lock().unwrap().clear_selection();
+                }
+            });
+        });
+        ui.separator();
+        
+        egui::ScrollArea::vertical()
+            .id_salt("logger_detail_scroll")
+            .auto_shrink([false, false])
+            .show(ui, |ui| {
+                // Entry fields
+                egui::Grid::new("logger_detail_fields")
+                    .num_columns(2)
+                    .spacing([10.0, 4.0])
+                    .show(ui, |ui| {
+                        ui.label(egui::RichText::new("Sequence").strong());
+                        ui.label(egui::RichText::new(log.sequence.to_string()).monospace());
+                        ui.end_row();
+                        
+                        ui.label(egui::RichText::new("Time").strong());
+                        ui.horizontal(|ui| {
+                            ui.label(egui::RichText::new(log.precise_timestamp()).monospace());
+                            if ui.small_button("📋").on_hover_text("Copy time").clicked() {
+                                ui.ctx().copy_text(log.precise_timestamp());
+                            }
+                        });
+                        ui.end_row();
+                        
+                        ui.label(egui::RichText::new("Level").strong());
+                        ui.label(egui::RichText::new(if level_text.is_empty() { "-".to_string() } else { level_text })
+                            .color(level_color)
+                            .monospace());
+                        ui.end_row();
+                        
+                        ui.label(egui::RichText::new("Source").strong());
+                        match log.location_text() {
+                            Some(location) => {
+                                ui.horizontal(|ui| {
+                                    ui.label(egui::RichText::new(&location).monospace());
+                                    if ui.small_button("📋").on_hover_text("Copy source location").clicked() {
+                                        ui.ctx().copy_text(location.clone());
+                                    }
+                                });
+                            }
+                            None => {
+                                ui.label("unknown");
+                            }
+                        }
+                        ui.end_row();
+                        
+                        if log.repeat_count > 1 {
+                            ui.label(egui::RichText::new("Repeats").strong());
+                            ui.label(format!("×{} (last {})",
+                                log.repeat_count,
+                                log.last_seen_at.format("%Y-%m-%d %H:%M:%S%.6f")));
+                            ui.end_row();
+                        }
+                        
+                        ui.label(egui::RichText::new("Length").strong());
+                        ui.label(format!("{} bytes, {} lines",
+                            log.log_message.content.value.len(),
+                            log.log_message.content.value.lines().count()));
+                        ui.end_row();
+                        
+                        ui.label(egui::RichText::new("Bookmark").strong());
+                        let mut bookmarked = log.bookmarked;
+                        if ui.checkbox(&mut bookmarked, "🔖 Bookmarked").changed()
+                            && let Some(arc) = ReactiveWidgetRef::from_dynamic(self.state).weak_ref.upgrade() {
+                            arc.lock().unwrap().toggle_bookmark(log.sequence);
+                        }
+                        ui.end_row();
+                        
+                        if let Some(path) = &log.archived {
+                            ui.label(egui::RichText::new("Archive").strong());
+                            ui.horizontal(|ui| {
+                                if ui.small_button("Expand from archive")
+                                    .on_hover_text(path.display().to_string())
+                                    .clicked() {
+                                    let text = read_archive(path)
+                                        .unwrap_or_else(|err| format!("Failed to read {}: {}", path.display(), err));
+                                    ui.ctx().data_mut(|data| data.insert_temp(archive_id, std::sync::Arc::new(text)));
+                                }
+                            });
+                            ui.end_row();
+                        }
+                    });
+                
+                ui.add_space(8.0);
+                
+                // Free-text note attached to the entry
+                ui.label(egui::RichText::new("Note").strong());
+                let mut note = log.note.clone().unwrap_or_default();
+                if ui.add(egui::TextEdit::multiline(&mut note)
+                        .id_salt(("logger_detail_note", log.sequence))
+                        .hint_text("Add a note to this entry")
+                        .desired_rows(2)
+                        .desired_width(f32::INFINITY))
+                    .changed()
+                    && let Some(arc) = ReactiveWidgetRef::from_dynamic(self.state).weak_ref.upgrade() {
+                    arc.lock().unwrap().set_note(log.sequence, &note);
+                }
+                
+                ui.add_space(8.0);
+                
+                // Full message, wrapped (expanded from the archive if loaded)
+                let expanded = ui.ctx().data(|data| data.get_temp::<std::sync::Arc<String>>(archive_id));
+                let message_text = expanded.as_deref().unwrap_or(&log.log_message.content.value);
+                ui.horizontal(|ui| {
+                    ui.label(egui::RichText::new("Message").strong());
+                    if ui.small_button("📋 Copy").on_hover_text("Copy message").clicked() {
+                        ui.ctx().copy_text(ansi::strip_ansi(message_text));
+                    }
+                });
+                
+                let formatted_message = if message_text.contains("SYSTEM DETAILS") {
+                    format_system_info(message_text)
+                } else {
+                    message_text.clone()
+                };
+                let message_color = get_log_message_color(log, &formatted_message, &colors);
+                
+                let json = if state.pretty_json {
+                    self.json_cache(ui.ctx(), state).get(log.sequence, &formatted_message)
+                } else {
+                    None
+                };
+                
+                egui::Frame::group(ui.style()).show(ui, |ui| {
+                    ui.set_width(ui.available_width());
+                    if let Some(json) = json {
+                        json_view::show_json_tree(ui, &json, &colors, ("logger_detail_json", log.sequence));
+                    } else if ansi::contains_ansi(&formatted_message) && !state.strip_ansi {
+                        let font_id = egui::TextStyle::Monospace.resolve(ui.style());
+                        ui.add(egui::Label::new(ansi::ansi_to_layout_job(&formatted_message, font_id, message_color)).wrap());
+                    } else {
+                        ui.add(egui::Label::new(
+                            egui::RichText::new(ansi::strip_ansi(&formatted_message))
+                                .color(message_color)
+                                .monospace()).wrap());
+                    }
+                });
+                
+                ui.add_space(8.0);
+                
+                // Raw JSON of the entry
+                let json = serde_json::to_string_pretty(&log.to_json()).unwrap_or_default();
+                ui.horizontal(|ui| {
+                    ui.label(egui::RichText::new("Raw JSON").strong());
+                    if ui.small_button("📋 Copy").on_hover_text("Copy as JSON").clicked() {
+                        ui.ctx().copy_text(json.clone());
+                    }
+                });
+                egui::Frame::group(ui.style()).show(ui, |ui| {
+                    ui.set_width(ui.available_width());
+                    ui.add(egui::Label::new(egui::RichText::new(json).monospace()).wrap());
+                });
+            });
+    }
+    
+    /// Handle clicks and the context menu of a table row
+    fn row_interaction(&self, response: &egui::Response, log: &LoggerPayload, state: &ReactiveEventLoggerState) -> Option<RowAction> {
+        let sequence = log.sequence;
+        let selected = state.selected.contains(&sequence);
+        let mut action = None;
+        
+        if response.clicked() {
+            let modifiers = response.ctx.input(|i| i.modifiers);
+            action = Some(RowAction::Click(sequence, modifiers));
+        } else if response.secondary_clicked() && !selected {
+            // Right-clicking an unselected row selects it first
+            action = Some(RowAction::Click(sequence, egui::Modifiers::NONE));
+        }
+        
+        response.context_menu(|ui| {
+            if selected && state.selected.len() > 1
+                && ui.button(format!("Copy {} selected rows", state.selected.len())).clicked() {
+                action = Some(RowAction::CopySelected);
+                ui.close_menu();
+            }
+            if ui.button("Copy message").clicked() {
+                action = Some(RowAction::CopyMessage(sequence));
+                ui.close_menu();
+            }
+            if ui.button("Copy as JSON").clicked() {
+                action = Some(RowAction::CopyJson(sequence));
+                ui.close_menu();
+            }
+            
+            ui.separator();
+            
+            let bookmark_text = if log.bookmarked { "Remove bookmark" } else { "🔖 Bookmark" };
+            if ui.button(bookmark_text).clicked() {
+                action = Some(RowAction::ToggleBookmark(sequence));
+                ui.close_menu();
+            }
+            
+            ui.separator();
+            
+            if ui.button("Filter to this type").clicked() {
+                action = Some(RowAction::FilterToType(sequence));
+                ui.close_menu();
+            }
+            if ui.button("Exclude messages like this").clicked() {
+                action = Some(RowAction::ExcludeLike(sequence));
+                ui.close_menu();
+            }
+            
+            // The group of a header is its own group, otherwise the innermost one
+            let group = if log.group.is_some() { Some(sequence) } else { log.groups.last().copied() };
+            if let Some(group) = group
+                && ui.button("Show only this group").clicked() {
+                action = Some(RowAction::FilterToGroup(group));
+                ui.close_menu();
+            }
+        });
+        
+        action
+    }
+    
+    /// Apply a row action to the shared state
+    fn apply_row_action(&self, ctx: &egui::Context, action: RowAction, order: &[u64]) {
+        let Some(arc) = ReactiveWidgetRef::from_dynamic(self.state).weak_ref.upgrade() else {
+            return;
+        };
+        let mut state = arc.lock().unwrap();
+        
+        match action {
+            RowAction::Click(sequence, modifiers) => {
+                state.select_log(sequence, modifiers, order);
+            }
+            RowAction::CopySelected => {
+                ctx.copy_text(state.selected_logs_text());
+            }
+            RowAction::CopyMessage(sequence) => {
+                if let Some(log) = state.find_log(sequence) {
+                    ctx.copy_text(ansi::strip_ansi(&log.log_message.content.value));
+                }
+            }
+            RowAction::CopyJson(sequence) => {
+                if let Some(log) = state.find_log(sequence) {
+                    ctx.copy_text(serde_json::to_string_pretty(&log.to_json()).unwrap_or_default());
+                }
+            }
+            RowAction::FilterToType(sequence) => {
+                if let Some(log) = state.find_log(sequence).cloned() {
+                    state.filter.show_only_type_of(&log);
+                    state.filter.save_to_memory_with_id(ctx, self.id_source);
+                }
+            }
+            RowAction::ToggleBookmark(sequence) => {
+                state.toggle_bookmark(sequence);
+            }
+            RowAction::ToggleGroup(sequence) => {
+                state.toggle_group(sequence);
+            }
+            RowAction::FilterToGroup(sequence) => {
+                state.filter.only_group = Some(sequence);
+            }
+            RowAction::ExcludeLike(sequence) => {
+                if let Some(log) = state.find_log(sequence).cloned() {
+                    state.filter.exclude_like(&log.log_message.content.value);
+                    state.filter.save_to_memory_with_id(ctx, self.id_source);
+                }
+            }
+        }
+    }
+    
+    /// Fallback to plain text display when colors are not available
+    fn show_plain_text_logs(&self, ui: &mut egui::Ui, state: &ReactiveEventLoggerState, filter: &LogFilter) {
+        // Get column visibility settings
+        let show_timestamps = state.show_timestamps;
+        let show_log_level = state.show_log_level;
+        let show_messages = state.show_messages;
+        
+        if !show_timestamps && !show_log_level && !show_messages {
+            // Nothing to show
+            ui.label("No columns selected");
+            return;
+        }
+        
+        // Calculate available height to fill the panel
+        let available_height = ui.available_height();
+        
+        let mut log_text = String::new();
+        
+        // Process the logs passing the pane's filter in display order
+        for log in state.displayed_logs_with(filter) {
+            if show_timestamps {
+                log_text.push_str(&format!("{} ", log.timestamp.value.value));
+            }
+            
+            if show_log_level {
+                // Find the non-empty log level
+                if !log.log_level.info.value.is_empty() {
+                    log_text.push_str(&format!("[{}] ", log.log_level.info.value));
+                } else if !log.log_level.debug.value.is_empty() {
+                    log_text.push_str(&format!("[{}] ", log.log_level.debug.value));
+                } else if !log.log_level.warning.value.is_empty() {
+                    log_text.push_str(&format!("[{}] ", log.log_level.warning.value));
+                } else if !log.log_level.error.value.is_empty() {
+                    log_text.push_str(&format!("[{}] ", log.log_level.error.value));
+                }
+            }
+            
+            if show_messages {
+                log_text.push_str(&ansi::strip_ansi(&log.log_message.content.value));
+            }
+            
+            log_text.push('\n');
+        }
+        
+        // Create a scrollable area for the plain text content
+        egui::ScrollArea::vertical()
+            .auto_shrink([false, false])
+            .stick_to_bottom(true)
+            .show(ui, |ui| {
+                // Show the logs in a monospace, non-interactive text editor that fills the space
+                egui::TextEdit::multiline(&mut log_text)
+                    .font(egui::TextStyle::Monospace)
+                    .desired_width(f32::INFINITY)
+                    .min_size(egui::vec2(ui.available_width(), available_height))
+                    .interactive(false)
+                    .show(ui);
+            });
+    }
+}
+
+// Helper function to create a clickable, non-text-selectable label for a table row
+fn row_label(text: impl Into<egui::WidgetText>) -> egui::Label {
+    egui::Label::new(text)
+        .selectable(false)
+        .sense(egui::Sense::click())
+}
+
+// Helper function to draw a table cell with a selection highlight behind it
+fn selectable_cell(ui: &mut egui::Ui, selected: bool, add_contents: impl FnOnce(&mut egui::Ui) -> egui::Response) -> egui::Response {
+    // Reserve a shape slot so the highlight is painted below the text
+    let background = ui.painter().add(egui::Shape::Noop);
+    let response = add_contents(ui);
+    
+    if selected {
+        let rect = response.rect.expand2(egui::vec2(5.0, 2.0));
+        ui.painter().set(background, egui::Shape::rect_filled(rect, 0.0, ui.visuals().selection.bg_fill));
+    }
+    
+    response
+}
+
+// Helper function to draw the message of a table row, including the repeat
+// badge of deduplicated entries, JSON previews and ANSI colors. `json` is the
+// message parsed as JSON if it is shown as such, `format` holds the row font
+// and the message color.
+fn message_cell(
+    ui: &mut egui::Ui,
+    log: &LoggerPayload,
+    formatted_message: String,
+    json: Option<std::sync::Arc<serde_json::Value>>,
+    format: egui::TextFormat,
+    colors: &LogColors,
+    highlight: Option<(&SearchMatcher, bool)>,
+) -> egui::Response {
+    // "×37" badge for merged duplicates
+    let badge = (log.repeat_count > 1).then(|| {
+        let badge_text = egui::RichText::new(format!("×{}", log.repeat_count))
+            .font(format.font_id.clone())
+            .strong()
+            .color(ui.visuals().strong_text_color())
+            .background_color(ui.visuals().faint_bg_color);
+        ui.add(row_label(badge_text))
+            .on_hover_text(format!(
+                "Repeated {} times\nFirst: {}\nLast:  {}",
+                log.repeat_count,
+                log.created_at.format("%Y-%m-%d %H:%M:%S%.3f"),
+                log.last_seen_at.format("%Y-%m-%d %H:%M:%S%.3f"),
+            ))
+    });
+    
+    let job = if let Some(json) = json {
+        // Compact single-line preview of JSON messages
+        json_view::json_preview_job(&json, format.font_id, colors, format.color)
+    } else if !ansi::contains_ansi(&formatted_message) {
+        egui::text::LayoutJob::single_section(formatted_message, format)
+    } else {
+        // Render SGR escape codes as colored sections
+        ansi::ansi_to_layout_job(&formatted_message, format.font_id, format.color)
+    };
+    
+    // Highlight search matches, more strongly in the current hit
+    let job = match highlight {
+        Some((matcher, current)) => {
+            matcher.highlight(job, if current { CURRENT_MATCH_BACKGROUND } else { MATCH_BACKGROUND })
+        }
+        None => job,
+    };
+    let response = ui.add(row_label(job));
+    
+    // Inline progress bar of a running operation
+    let response = match &log.progress {
+        Some(progress) => {
+            let bar = egui::ProgressBar::new(progress.fraction())
+                .desired_width(240.0)
+                .desired_height(16.0)
+                .text(progress.status_text(std::time::Instant::now()));
+            response.union(ui.add(bar))
+        }
+        None => response,
+    };
+    
+    union_response(badge, response)
+}
+
+// Helper function to shorten a text for menus and toasts
+fn shorten_text(text: &str, max_chars: usize) -> String {
+    if text.chars().count() > max_chars {
+        text.chars().take(max_chars).collect::<String>() + "…"
+    } else {
+        text.to_string()
+    }
+}
+
+// Helper function to combine the responses of the cells of a row
+fn union_response(row: Option<egui::Response>, cell: egui::Response) -> egui::Response {
+    match row {
+        Some(row) => row.union(cell),
+        None => cell,
+    }
+}
+
+// Helper function to get log level text and color
+pub fn get_log_level_text_and_color(log: &LoggerPayload, colors: &LogColors) -> (String, egui::Color32) {
+    if !log.log_level.info.value.is_empty() {
         // Check if it's a custom type (starts with "CUSTOM:")
         if log.log_level.info.value.starts_with("CUSTOM:") {
             let identifier = log.log_level.info.value.strip_prefix("CUSTOM:").unwrap_or("");
             (format!("[CUSTOM:{}]", identifier), colors.get_custom_color_level(identifier))
+        } else if LogType::Command.matches(log) {
+            (format!("[{}]", log.log_level.info.value), colors.command)
         } else {
             (format!("[{}]", log.log_level.info.value), colors.info_level)
         }
@@ -1666,6 +4678,34 @@ pub fn get_log_level_text_and_color(log: &LoggerPayload, colors: &LogColors) ->
     }
 }
 
+// Helper function to get the message color of a log, based on its log level
+// first and falling back to the message content
+pub fn get_log_message_color(log: &LoggerPayload, formatted_message: &str, colors: &LogColors) -> egui::Color32 {
+    if !log.log_level.info.value.is_empty() {
+        // Check if it's a custom type
+        if let Some(identifier) = log.log_level.info.value.strip_prefix("CUSTOM:") {
+            colors.get_custom_color_message(identifier)
+        } else if LogType::Command.matches(log) {
+            if log.log_message.content.value.starts_with(COMMAND_ERROR_MARKER) {
+                colors.error_message
+            } else {
+                colors.command
+            }
+        } else {
+            colors.info_message
+        }
+    } else if !log.log_level.warning.value.is_empty() {
+        colors.warning_message
+    } else if !log.log_level.error.value.is_empty() {
+        colors.error_message
+    } else if !log.log_level.debug.value.is_empty() {
+        colors.debug_message
+    } else {
+        // Fallback to content-based detection
+        get_message_color(formatted_message, colors)
+    }
+}
+
 // Helper function to get message color
 pub fn get_message_color(message_text: &str, colors: &LogColors) -> egui::Color32 {
     // Determine message type based on content
@@ -1679,11 +4719,10 @@ pub fn get_message_color(message_text: &str, colors: &LogColors) -> egui::Color3
         colors.debug_message
     } else if message_text.contains("[CUSTOM:") {
         // Extract the custom identifier from format like "[CUSTOM:mytype]"
-        if let Some(start) = message_text.find("[CUSTOM:") {
-            if let Some(end) = message_text[start..].find("]") {
-                let custom_type = &message_text[start + 8..start + end];
-                return colors.get_custom_color_message(custom_type);
-            }
+        if let Some(start) = message_text.find("[CUSTOM:")
+            && let Some(end) = message_text[start..].find("]") {
+            let custom_type = &message_text[start + 8..start + end];
+            return colors.get_custom_color_message(custom_type);
         }
         colors.default
     } else {
@@ -1692,6 +4731,21 @@ pub fn get_message_color(message_text: &str, colors: &LogColors) -> egui::Color3
     }
 }
 
+// Helper function to get the message of a row as the table shows it, which
+// is the text search matches are highlighted in
+fn rendered_message(log: &LoggerPayload, pretty_json: bool) -> String {
+    let message_text = &log.log_message.content.value;
+    let formatted_message = if message_text.contains("SYSTEM DETAILS") {
+        format_system_info(message_text)
+    } else {
+        message_text.clone()
+    };
+    match pretty_json.then(|| json_view::parse_json_message(&formatted_message)).flatten() {
+        Some(json) => json_view::json_preview_text(&json),
+        None => ansi::strip_ansi(&formatted_message),
+    }
+}
+
 // Helper function to format system info
 pub fn format_system_info(message: &str) -> String {
     // Split the message into lines and align key-value pairs
@@ -1718,5 +4772,1100 @@ pub fn is_any_filter_active(filter: &LogFilter) -> bool {
     !filter.show_custom || 
     !filter.show_system ||
     // Check if text filter is active
-    !filter.text_filter.is_empty()
-}
\ No newline at end of file
+    !filter.text_filter.is_empty() ||
+    // Check if custom types are restricted or messages excluded
+    filter.only_custom_type.is_some() ||
+    !filter.excluded_patterns.is_empty() ||
+    filter.only_bookmarked ||
+    filter.only_group.is_some()
+}
+
+// Helper function to reduce a message to a pattern for "messages like this"
+// matching: ANSI codes are removed and runs of digits collapse to '#', so
+// "Retry 3 of 10" and "Retry 4 of 10" share the pattern "Retry # of #"
+pub fn message_pattern(message: &str) -> String {
+    let mut pattern = String::new();
+    let mut in_digits = false;
+    for c in ansi::strip_ansi(message).chars() {
+        if c.is_ascii_digit() {
+            if !in_digits {
+                pattern.push('#');
+            }
+            in_digits = true;
+        } else {
+            pattern.push(c);
+            in_digits = false;
+        }
+    }
+    pattern
+}
+
+// Helper function to format a single log line in the export text format
+pub fn format_log_line(log: &LoggerPayload) -> String {
+    let mut line = String::new();
+    
+    // Add timestamp if available
+    if !log.timestamp.value.value.is_empty() {
+        line.push_str(&format!("[{}] ", log.timestamp.value.value));
+    }
+    
+    // Add log level if available
+    if !log.level().is_empty() {
+        line.push_str(&format!("[{}] ", log.level()));
+    }
+    
+    // Add the channel if available
+    if let Some(channel) = &log.channel {
+        line.push_str(&format!("[{}] ", channel));
+    }
+    
+    // Indent entries nested in operation groups
+    line.push_str(&"  ".repeat(log.groups.len()));
+    
+    // Add message (escape codes are meaningless in a text file)
+    line.push_str(&ansi::strip_ansi(&log.log_message.content.value));
+    
+    // Add the duration of a closed operation group
+    if let Some(duration) = log.group.as_ref().and_then(|group| group.duration) {
+        line.push_str(&format!(" (took {})", format_duration(duration)));
+    }
+    
+    // Add repeat information of deduplicated entries
+    if log.repeat_count > 1 {
+        line.push_str(&format!(" (repeated {} times, last at {})",
+            log.repeat_count,
+            log.last_seen_at.format("%Y-%m-%d %H:%M:%S")));
+    }
+    
+    // Add the state of a running operation
+    if let Some(progress) = &log.progress {
+        line.push_str(&format!(" ({})", progress.status_text(std::time::Instant::now())));
+    }
+    
+    // Add the user's note
+    if let Some(note) = &log.note {
+        line.push_str(&format!(" (note: {})", note));
+    }
+    line
+}
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn state_with_logs(count: usize) -> ReactiveEventLoggerState {
+        let mut state = ReactiveEventLoggerState::new();
+        for i in 0..count {
+            let mut payload = LoggerPayload::new();
+            payload.info().message(format!("message {}", i)).update();
+            state.add_log(payload);
+        }
+        state
+    }
+
+    #[test]
+    fn test_sequence_numbers_survive_eviction() {
+        let mut state = state_with_logs(5);
+        state.set_max_logs(3);
+        state.add_log(LoggerPayload::new());
+        
+        let sequences: Vec<u64> = state.logs.iter().map(|log| log.sequence).collect();
+        assert_eq!(sequences, vec![4, 5, 6]);
+        assert_eq!(state.find_log(5).map(|log| log.log_message.content.value.as_str()), Some("message 4"));
+        assert!(state.find_log(1).is_none());
+    }
+
+    #[test]
+    fn test_select_log_click_modifiers() {
+        let mut state = state_with_logs(5);
+        // Newest first, like the table
+        let order = [5, 4, 3, 2, 1];
+        
+        state.select_log(4, egui::Modifiers::NONE, &order);
+        assert_eq!(state.selected.iter().copied().collect::<Vec<_>>(), vec![4]);
+        
+        state.select_log(2, egui::Modifiers::SHIFT, &order);
+        assert_eq!(state.selected.iter().copied().collect::<Vec<_>>(), vec![2, 3, 4]);
+        
+        state.select_log(3, egui::Modifiers::COMMAND, &order);
+        assert_eq!(state.selected.iter().copied().collect::<Vec<_>>(), vec![2, 4]);
+        
+        assert_eq!(state.selected_logs_text().lines().count(), 2);
+        assert!(state.selected_logs_text().ends_with("[INFO] message 3"));
+    }
+
+    #[test]
+    fn test_retention_keeps_rare_levels() {
+        let mut state = ReactiveEventLoggerState::new();
+        state.set_max_logs(100);
+        state.retention.push(RetentionRule::level(LogType::Debug).keep_last(10));
+        
+        let mut payload = LoggerPayload::new();
+        payload.error().message("disk failed".to_string()).update();
+        state.add_log(payload);
+        for i in 0..500 {
+            let mut payload = LoggerPayload::new();
+            payload.debug().message(format!("poll {}", i)).update();
+            state.add_log(payload);
+        }
+        
+        assert_eq!(state.level_counts(), vec![("ERROR".to_string(), 1), ("DEBUG".to_string(), 10)]);
+        assert_eq!(state.logs.last().unwrap().log_message.content.value, "poll 499");
+        
+        // With a full buffer, the rules evict before the buffer size does
+        state.set_max_logs(11);
+        let mut payload = LoggerPayload::new();
+        payload.debug().message("poll 500".to_string()).update();
+        state.add_log(payload);
+        assert_eq!(state.level_counts(), vec![("ERROR".to_string(), 1), ("DEBUG".to_string(), 10)]);
+        assert_eq!(state.logs[0].log_message.content.value, "disk failed");
+    }
+
+    #[test]
+    fn test_memory_budget() {
+        let mut state = ReactiveEventLoggerState::new();
+        state.memory_budget = Some(MemoryBudget::new(4096).max_message_bytes(100).archive_dir(None));
+        for i in 0..100 {
+            let mut payload = LoggerPayload::new();
+            payload.info().message(format!("{} {}", i, "x".repeat(1000))).update();
+            state.add_log(payload);
+        }
+        
+        assert!(state.memory_usage() <= 4096);
+        assert_eq!(state.memory_usage(), state.logs.iter().map(LoggerPayload::heap_size).sum::<usize>());
+        assert!(state.log_count() > 1);
+        let newest = state.logs.last().unwrap();
+        assert!(newest.log_message.content.value.starts_with("99 xxx"));
+        assert!(newest.log_message.content.value.ends_with(crate::memory::TRUNCATION_MARKER));
+        assert!(newest.archived.is_none());
+        
+        state.clear_logs();
+        assert_eq!(state.memory_usage(), 0);
+    }
+
+    #[test]
+    fn test_archive_files_are_deleted() {
+        let dir = std::env::temp_dir().join(format!("egui_lens_eviction_test_{}", std::process::id()));
+        let mut state = ReactiveEventLoggerState::new();
+        state.set_max_logs(2);
+        state.memory_budget = Some(MemoryBudget::new(1 << 20).max_message_bytes(10).archive_dir(Some(dir.clone())));
+        for i in 0..3 {
+            let mut payload = LoggerPayload::new();
+            payload.info().message(format!("{} {}", i, "x".repeat(100))).update();
+            state.add_log(payload);
+        }
+        let archives: Vec<std::path::PathBuf> = state.logs.iter().filter_map(|log| log.archived.clone()).collect();
+        assert_eq!(archives.len(), 2);
+        assert!(read_archive(&archives[0]).unwrap().starts_with("1 xxx"));
+        
+        // Only the archives of the stored entries are left, until they are cleared
+        assert_eq!(std::fs::read_dir(&dir).unwrap().count(), 2);
+        state.clear_logs();
+        assert!(archives.iter().all(|path| read_archive(path).is_err()));
+        
+        let _ = std::fs::remove_dir_all(dir);
+    }
+
+    #[test]
+    fn test_filter_presets() {
+        let mut state = ReactiveEventLoggerState::new();
+        state.filter.text_filter = "timeout".to_string();
+        state.save_filter_preset("Timeouts");
+        assert_eq!(state.active_preset_label().as_deref(), Some("Timeouts"));
+        
+        assert!(state.apply_filter_preset("Everything"));
+        assert!(state.filter.text_filter.is_empty());
+        state.filter.show_debug = false;
+        assert_eq!(state.active_preset_label().as_deref(), Some("Everything (modified)"));
+        
+        assert!(state.rename_filter_preset("Everything", "All"));
+        assert_eq!(state.active_preset.as_deref(), Some("All"));
+        state.delete_filter_preset("All");
+        assert!(state.active_preset.is_none());
+        
+        assert!(state.apply_filter_preset("Timeouts"));
+        assert_eq!(state.filter.text_filter, "timeout");
+    }
+
+    #[test]
+    fn test_follow_and_row_order() {
+        let mut state = state_with_logs(3);
+        let order = |state: &ReactiveEventLoggerState| {
+            state.displayed_logs().iter().map(|log| log.sequence).collect::<Vec<_>>()
+        };
+        assert_eq!(order(&state), vec![3, 2, 1]);
+        state.row_order = RowOrder::OldestFirst;
+        assert_eq!(order(&state), vec![1, 2, 3]);
+        
+        state.pause_follow();
+        for _ in 0..2 {
+            let mut payload = LoggerPayload::new();
+            payload.debug().message("new".to_string()).update();
+            state.add_log(payload);
+        }
+        assert_eq!(state.new_entries_since_pause(), 2);
+        state.filter.show_debug = false;
+        assert_eq!(state.new_entries_since_pause(), 0, "only displayed entries count");
+        
+        state.resume_follow();
+        assert!(state.follow);
+        assert_eq!(state.new_entries_since_pause(), 0);
+    }
+
+    #[test]
+    fn test_freeze() {
+        let mut state = state_with_logs(3);
+        state.freeze();
+        for i in 0..4 {
+            let mut payload = LoggerPayload::new();
+            payload.info().message(format!("after freeze {}", i)).update();
+            state.add_log(payload);
+        }
+        
+        assert_eq!(state.log_count(), 7);
+        assert_eq!(state.displayed_logs().len(), 3);
+        assert_eq!(state.received_since_freeze(), 4);
+        
+        state.unfreeze();
+        assert_eq!(state.displayed_logs().len(), 7);
+        assert_eq!(state.received_since_freeze(), 0);
+    }
+
+    #[test]
+    fn test_freeze_keeps_snapshot() {
+        let mut state = state_with_logs(5);
+        state.set_max_logs(5);
+        state.deduplication = Deduplication::Consecutive;
+        state.freeze();
+        
+        // A burst into the full buffer is kept, and a duplicate of the last
+        // frozen row gets a row of its own
+        for i in 0..4 {
+            let mut payload = LoggerPayload::new();
+            payload.info().message(format!("after freeze {}", i)).update();
+            state.add_log(payload);
+        }
+        let mut payload = LoggerPayload::new();
+        payload.info().message("message 4".to_string()).update();
+        state.add_log(payload);
+        
+        let frozen: Vec<&str> = state.displayed_logs()
+            .iter()
+            .map(|log| log.log_message.content.value.as_str())
+            .collect();
+        assert_eq!(frozen, vec!["message 4", "message 3", "message 2", "message 1", "message 0"]);
+        assert!(state.displayed_logs().iter().all(|log| log.repeat_count == 1));
+        assert_eq!(state.log_count(), 10);
+        assert_eq!(state.received_since_freeze(), 5);
+        
+        // Once unfrozen, the oldest entries are evicted and the burst is shown
+        state.unfreeze();
+        let sequences: Vec<u64> = state.logs.iter().map(|log| log.sequence).collect();
+        assert_eq!(sequences, vec![6, 7, 8, 9, 10]);
+        
+        // The buffer grows to twice its size at most, evicting the oldest rows
+        state.freeze();
+        for i in 0..8 {
+            let mut payload = LoggerPayload::new();
+            payload.info().message(format!("flood {}", i)).update();
+            state.add_log(payload);
+        }
+        assert_eq!(state.log_count(), 10);
+        assert_eq!(state.logs[0].sequence, 9);
+        assert_eq!(state.received_since_freeze(), 8);
+    }
+
+    #[test]
+    fn test_bookmarks_and_notes() {
+        let mut state = state_with_logs(4);
+        state.toggle_bookmark(2);
+        state.toggle_bookmark(4);
+        state.toggle_bookmark(4);
+        state.set_note(3, "looks suspicious");
+        state.set_note(1, "  ");
+        
+        assert_eq!(state.bookmarked_logs().iter().map(|log| log.sequence).collect::<Vec<_>>(), vec![2]);
+        assert_eq!(state.find_log(3).unwrap().note.as_deref(), Some("looks suspicious"));
+        assert!(state.find_log(1).unwrap().note.is_none());
+        
+        state.filter.only_bookmarked = true;
+        assert_eq!(state.displayed_logs().len(), 1);
+        
+        // Both are edits, and notes count towards the memory used
+        assert_eq!(state.edits, 5);
+        let memory_used = state.memory_used;
+        state.set_note(2, "worth a second look");
+        assert_eq!(state.memory_used, memory_used + "worth a second look".len());
+        
+        // Bookmarks and notes survive a session round trip
+        let path = std::env::temp_dir().join(format!("egui_lens_session_test_{}.json", std::process::id()));
+        state.save_session(&path).unwrap();
+        let mut restored = ReactiveEventLoggerState::new();
+        restored.load_session(&path).unwrap();
+        let _ = std::fs::remove_file(path);
+        
+        assert_eq!(restored.log_count(), 4);
+        assert!(restored.find_log(2).unwrap().bookmarked);
+        assert_eq!(restored.find_log(3).unwrap().note.as_deref(), Some("looks suspicious"));
+        assert_eq!(restored.find_log(4).unwrap().log_message.content.value, "message 3");
+    }
+
+    #[test]
+    fn test_progress_entry() {
+        let state = Dynamic::new(ReactiveEventLoggerState::new());
+        state.lock().deduplication = Deduplication::Consecutive;
+        let logger = ReactiveEventLogger::new(&state);
+        
+        let handle = logger.progress("Wiping", 200);
+        handle.set(50);
+        {
+            let state = state.get();
+            let progress = state.find_log(handle.sequence()).unwrap().progress.as_ref().unwrap();
+            assert_eq!(state.log_count(), 1);
+            assert!(LogType::Progress.matches(state.find_log(handle.sequence()).unwrap()));
+            assert_eq!(progress.fraction(), 0.25);
+        }
+        
+        // A second operation with the same label gets its own entry
+        let failing = logger.progress("Wiping", 10);
+        handle.finish_ok();
+        drop(failing);
+        
+        let state = state.get();
+        assert_eq!(state.log_count(), 2);
+        assert!(!state.has_active_progress());
+        assert_eq!(state.logs[0].level(), "INFO");
+        assert!(state.logs[0].log_message.content.value.starts_with("Wiping: done (200 in"));
+        assert_eq!(state.logs[1].level(), "ERROR");
+        assert!(state.logs[1].log_message.content.value.ends_with("stopped before completion"));
+    }
+
+    #[test]
+    fn test_operation_groups() {
+        let state = Dynamic::new(ReactiveEventLoggerState::new());
+        let logger = ReactiveEventLogger::new(&state);
+        
+        let (outer, inner) = {
+            let outer = logger.group("Format SD card");
+            logger.log_info("wiping");
+            let inner = {
+                let inner = logger.group("Write filesystem");
+                logger.log_warning("bad sector");
+                inner.sequence()
+            };
+            logger.log_info("done");
+            (outer.sequence(), inner)
+        };
+        logger.log_info("after the group");
+        
+        let mut state = state.lock();
+        assert!(state.open_groups.is_empty());
+        let groups: Vec<Vec<u64>> = state.logs.iter().map(|log| log.groups.clone()).collect();
+        assert_eq!(groups, vec![vec![], vec![outer], vec![outer], vec![outer, inner], vec![outer], vec![]]);
+        
+        // Closed groups record a duration and take on the worst severity
+        let header = state.find_log(outer).unwrap();
+        assert_eq!(header.level(), "WARNING");
+        assert_eq!(header.group.as_ref().unwrap().worst_level.as_deref(), Some("WARNING"));
+        assert!(header.group.as_ref().unwrap().duration.is_some());
+        
+        state.toggle_group(inner);
+        assert_eq!(state.displayed_logs().len(), 5);
+        state.toggle_group(inner);
+        
+        state.filter.only_group = Some(inner);
+        assert_eq!(state.displayed_logs().iter().map(|log| log.sequence).collect::<Vec<_>>(), vec![inner + 1, inner]);
+    }
+
+    #[test]
+    fn test_operation_groups_are_per_thread() {
+        let state = Dynamic::new(ReactiveEventLoggerState::new());
+        let logger = ReactiveEventLogger::new(&state);
+        
+        // A worker's group doesn't capture entries of other threads, and the
+        // other way round
+        let ui_group = logger.group("Refresh devices");
+        let worker_group = std::thread::scope(|scope| {
+            scope.spawn(|| {
+                let logger = ReactiveEventLogger::new(&state);
+                let group = logger.group("Format SD card");
+                logger.log_info("wiping");
+                (group.sequence(), group)
+            }).join().unwrap()
+        });
+        logger.log_info("device list updated");
+        drop(ui_group);
+        
+        let (worker_sequence, worker_guard) = worker_group;
+        assert_eq!(state.lock().open_groups.len(), 1);
+        drop(worker_guard);
+        
+        let state = state.lock();
+        assert!(state.open_groups.is_empty());
+        let groups: Vec<Vec<u64>> = state.logs.iter().map(|log| log.groups.clone()).collect();
+        assert_eq!(groups, vec![vec![], vec![], vec![worker_sequence], vec![1]]);
+    }
+
+    #[test]
+    fn test_two_loggers_in_one_frame() {
+        let ctx = egui::Context::default();
+        let state_a = Dynamic::new(state_with_logs(3));
+        let state_b = Dynamic::new(state_with_logs(3));
+        let logger_a = ReactiveEventLogger::new(&state_a).id_source("logger_a");
+        let logger_b = ReactiveEventLogger::new(&state_b).id_source("logger_b");
+        let run_frame = || {
+            let _ = ctx.run(egui::RawInput::default(), |ctx| {
+                egui::CentralPanel::default().show(ctx, |ui| {
+                    logger_a.show(ui);
+                    logger_b.show(ui);
+                });
+            });
+        };
+        
+        // Opening the filter modal of one logger leaves the other one closed
+        run_frame();
+        ctx.data_mut(|data| data.insert_temp(logger_a.id("show_logger_filter_modal"), true));
+        run_frame();
+        assert!(ctx.memory(|mem| mem.area_rect(logger_a.id("logger_filter_modal"))).is_some());
+        assert!(ctx.memory(|mem| mem.area_rect(logger_b.id("logger_filter_modal"))).is_none());
+        
+        // Persisted filters are kept per logger
+        let errors_only = LogFilter { show_info: false, ..LogFilter::default() };
+        errors_only.save_to_memory_with_id(&ctx, logger_a.id_source);
+        let mut filter_b = LogFilter::default();
+        filter_b.load_from_memory_with_id(&ctx, logger_b.id_source);
+        assert!(filter_b.show_info);
+        let mut filter_a = LogFilter::default();
+        filter_a.load_from_memory_with_id(&ctx, logger_a.id_source);
+        assert_eq!(filter_a, errors_only);
+    }
+
+    #[test]
+    #[allow(deprecated)]
+    fn test_filters_saved_under_global_keys_are_migrated() {
+        let ctx = egui::Context::default();
+        let errors_only = LogFilter { show_info: false, text_filter: "disk".to_string(), ..LogFilter::default() };
+        errors_only.save_to_memory(&ctx);
+        
+        let mut loaded = LogFilter::default();
+        loaded.load_from_memory(&ctx);
+        assert_eq!(loaded, errors_only);
+        
+        // A logger without saved filters of its own starts from the old ones
+        let mut scoped = LogFilter::default();
+        scoped.load_from_memory_with_id(&ctx, egui::Id::new("logger_a"));
+        assert_eq!(scoped, errors_only);
+        
+        // Once the logger saves its own filters they take precedence
+        let all = LogFilter::default();
+        all.save_to_memory_with_id(&ctx, egui::Id::new("logger_a"));
+        scoped.load_from_memory_with_id(&ctx, egui::Id::new("logger_a"));
+        assert_eq!(scoped, all);
+    }
+    
+    #[test]
+    fn test_shortcuts_act_on_hovered_logger() {
+        let ctx = egui::Context::default();
+        let state_a = Dynamic::new(state_with_logs(3));
+        let state_b = Dynamic::new(state_with_logs(3));
+        state_a.lock().selected.insert(1);
+        state_b.lock().selected.insert(1);
+        let logger_a = ReactiveEventLogger::new(&state_a).id_source("logger_a");
+        let logger_b = ReactiveEventLogger::new(&state_b).id_source("logger_b");
+        let command_key = |key| egui::Event::Key { key, physical_key: None, pressed: true, repeat: false, modifiers: egui::Modifiers::COMMAND };
+        let run_frame = |events: Vec<egui::Event>| {
+            let input = egui::RawInput {
+                screen_rect: Some(egui::Rect::from_min_size(egui::Pos2::ZERO, egui::vec2(1200.0, 800.0))),
+                events,
+                ..Default::default()
+            };
+            let _ = ctx.run(input, |ctx| {
+                egui::CentralPanel::default().show(ctx, |ui| {
+                    ui.columns(2, |columns| {
+                        logger_a.show(&mut columns[0]);
+                        logger_b.show(&mut columns[1]);
+                    });
+                });
+            });
+        };
+        
+        // Point at the left logger, then press Ctrl+B and Ctrl+F
+        run_frame(vec![egui::Event::PointerMoved(egui::pos2(300.0, 400.0))]);
+        run_frame(vec![command_key(egui::Key::B)]);
+        assert!(state_a.lock().logs[0].bookmarked);
+        assert!(!state_b.lock().logs[0].bookmarked);
+        
+        run_frame(vec![command_key(egui::Key::F)]);
+        assert!(state_a.lock().search.open);
+        assert!(!state_b.lock().search.open);
+    }
+
+    #[test]
+    fn test_channels() {
+        let state = Dynamic::new(ReactiveEventLoggerState::new());
+        let logger = ReactiveEventLogger::new(&state);
+        
+        logger.channel("device").log_info("port opened");
+        logger.channel("jobs").log_error("backup failed");
+        logger.channel("device").log_error("read timeout");
+        logger.log_info("no channel");
+        
+        let mut state = state.lock();
+        assert_eq!(state.channels, vec!["device".to_string(), "jobs".to_string()]);
+        assert_eq!(state.channel_unread("device"), (2, 1));
+        assert_eq!(state.channel_unread("jobs"), (1, 1));
+        
+        state.set_active_channel(Some("device"));
+        assert_eq!(state.channel_unread("device"), (0, 0));
+        assert_eq!(state.displayed_logs().len(), 2);
+        
+        // The merged view is ordered by creation time
+        state.set_active_channel(None);
+        state.logs[0].created_at += chrono::Duration::seconds(10);
+        let messages: Vec<&str> = state.displayed_logs()
+            .iter()
+            .map(|log| log.log_message.content.value.as_str())
+            .collect();
+        assert_eq!(messages, vec!["port opened", "no channel", "read timeout", "backup failed"]);
+    }
+
+    #[test]
+    fn test_split_filter() {
+        let state = Dynamic::new(ReactiveEventLoggerState::new());
+        let logger = ReactiveEventLogger::new(&state);
+        logger.log_custom("network", "request sent");
+        logger.log_custom("database", "query started");
+        logger.log_custom("network", "response received");
+        
+        let mut state = state.lock();
+        state.split_view = SplitView::SideBySide;
+        state.filter.only_custom_type = Some("network".to_string());
+        state.split_filter.only_custom_type = Some("database".to_string());
+        
+        assert_eq!(state.displayed_logs().len(), 2);
+        let second_pane = state.displayed_logs_with(&state.split_filter);
+        assert_eq!(second_pane.len(), 1);
+        assert_eq!(second_pane[0].log_message.content.value, "query started");
+        drop(state);
+        
+        // Without colors, each pane shows its own entries as plain text
+        let ctx = egui::Context::default();
+        let mut texts = Vec::new();
+        for _ in 0..2 {
+            let input = egui::RawInput {
+                screen_rect: Some(egui::Rect::from_min_size(egui::Pos2::ZERO, egui::vec2(1200.0, 800.0))),
+                ..Default::default()
+            };
+            let output = ctx.run(input, |ctx| {
+                egui::CentralPanel::default().show(ctx, |ui| logger.show(ui));
+            });
+            texts = output.shapes
+                .iter()
+                .filter_map(|clipped| match &clipped.shape {
+                    egui::Shape::Text(text) => Some(text.galley.job.text.clone()),
+                    _ => None,
+                })
+                .filter(|text| text.contains("request") || text.contains("query"))
+                .collect();
+        }
+        assert_eq!(texts.len(), 2);
+        assert!(texts.iter().any(|text| text.contains("request sent") && !text.contains("query")));
+        assert!(texts.iter().any(|text| text.contains("query started") && !text.contains("request")));
+    }
+
+    #[test]
+    fn test_pop_out() {
+        let ctx = egui::Context::default();
+        let state = Dynamic::new(state_with_logs(3));
+        let logger = ReactiveEventLogger::new(&state);
+        let run_frame = || {
+            let _ = ctx.run(egui::RawInput::default(), |ctx| {
+                egui::CentralPanel::default().show(ctx, |ui| logger.show(ui));
+            });
+        };
+        let window_layer = egui::LayerId::new(egui::Order::Middle, logger.id("logger_popout_window"));
+        let window_visible = || ctx.memory(|mem| mem.areas().is_visible(&window_layer));
+        
+        // Without a multi-window backend the popped out logger is an egui window
+        run_frame();
+        assert!(!window_visible());
+        state.lock().popped_out = true;
+        run_frame();
+        assert!(window_visible());
+        
+        // The window stays open in a frame without the panel, e.g. while
+        // its dock tab is in the background
+        let _ = ctx.run(egui::RawInput::default(), |ctx| {
+            egui::CentralPanel::default().show(ctx, |_| {});
+            logger.show_viewport(ctx);
+        });
+        assert!(window_visible());
+        
+        // Shown by both the panel and the app, it is shown once
+        let _ = ctx.run(egui::RawInput::default(), |ctx| {
+            egui::CentralPanel::default().show(ctx, |ui| logger.show(ui));
+            logger.show_viewport(ctx);
+        });
+        assert!(window_visible());
+        
+        // Docking back removes the window
+        state.lock().popped_out = false;
+        run_frame();
+        assert!(!window_visible());
+    }
+
+    #[test]
+    fn test_logger_config() {
+        let state = Dynamic::new(state_with_logs(10));
+        let config = LoggerConfig::new()
+            .order(RowOrder::OldestFirst)
+            .max_logs(5)
+            .columns(crate::config::LogColumns { timestamps: false, ..Default::default() });
+        
+        let _ = ReactiveEventLogger::new(&state).config(config.clone());
+        {
+            let state = state.lock();
+            assert_eq!(state.log_count(), 5);
+            assert_eq!(state.max_logs, 5);
+            assert_eq!(state.row_order, RowOrder::OldestFirst);
+            assert!(!state.show_timestamps);
+        }
+        
+        // The same config again keeps what the user changed in the panel
+        state.lock().row_order = RowOrder::NewestFirst;
+        let _ = ReactiveEventLogger::new(&state).config(config.clone());
+        assert_eq!(state.lock().row_order, RowOrder::NewestFirst);
+        
+        // A changed config is applied
+        let _ = ReactiveEventLogger::new(&state).config(config.max_logs(3));
+        assert_eq!(state.lock().row_order, RowOrder::OldestFirst);
+        assert_eq!(state.lock().log_count(), 3);
+        
+        // A config asking for an empty buffer keeps the newest entry, and is
+        // not applied again every time
+        let empty: LoggerConfig = serde_json::from_str(r#"{"max_logs": 0}"#).unwrap();
+        let _ = ReactiveEventLogger::new(&state).config(empty.clone());
+        assert_eq!(state.lock().max_logs, 1);
+        assert_eq!(state.lock().log_count(), 1);
+        state.lock().row_order = RowOrder::OldestFirst;
+        let _ = ReactiveEventLogger::new(&state).config(empty);
+        assert_eq!(state.lock().row_order, RowOrder::OldestFirst);
+        state.lock().set_max_logs(0);
+        assert_eq!(state.lock().max_logs, 1);
+    }
+
+    #[test]
+    fn test_toolbar_actions() {
+        let state = Dynamic::new(ReactiveEventLoggerState::new());
+        let mut logger_state = state.lock();
+        logger_state.add_toolbar_action(ToolbarAction::button("Ping", |state| {
+            ReactiveEventLogger::new(state).log_info("pong");
+        }));
+        logger_state.add_toolbar_action(ToolbarAction::menu("Tools", |_, _| {}));
+        
+        // Registering the same label again replaces the action
+        logger_state.add_toolbar_action(ToolbarAction::button("Ping", |state| {
+            ReactiveEventLogger::new(state).log_info("pong again");
+        }));
+        let labels: Vec<&str> = logger_state.toolbar_actions.iter().map(|action| action.label.as_str()).collect();
+        assert_eq!(labels, ["Ping", "Tools"]);
+        
+        // The callback gets the shared state
+        let action = logger_state.toolbar_actions[0].clone();
+        drop(logger_state);
+        if let crate::toolbar::ToolbarActionKind::Button(on_click) = &action.kind {
+            on_click(&state);
+        }
+        assert_eq!(state.lock().logs[0].log_message.content.value, "pong again");
+        
+        assert!(state.lock().remove_toolbar_action("Tools"));
+        assert!(!state.lock().remove_toolbar_action("Tools"));
+    }
+
+    #[test]
+    fn test_hooks() {
+        use std::sync::Arc;
+        use std::sync::atomic::{AtomicUsize, Ordering};
+        
+        let entries = Arc::new(AtomicUsize::new(0));
+        let errors = Arc::new(AtomicUsize::new(0));
+        let timeouts = Arc::new(AtomicUsize::new(0));
+        let mut state = ReactiveEventLoggerState::new();
+        let counter = entries.clone();
+        let entry_hook = state.on_entry(move |_| { counter.fetch_add(1, Ordering::SeqCst); });
+        let counter = errors.clone();
+        state.on_level(LogType::Error, move |_| { counter.fetch_add(1, Ordering::SeqCst); });
+        let counter = timeouts.clone();
+        state.on_match(r"time(d)? ?out", move |log| {
+            assert!(log.sequence > 0);
+            counter.fetch_add(1, Ordering::SeqCst);
+        }).unwrap();
+        assert!(state.on_match("(", |_| {}).is_err());
+        
+        let mut payload = LoggerPayload::new();
+        payload.info().message("connected".to_string()).update();
+        state.add_log(payload);
+        let mut payload = LoggerPayload::new();
+        payload.error().message("read timed out".to_string()).update();
+        state.add_log(payload);
+        
+        // Calls wait until the caller runs them, after unlocking the state
+        assert_eq!(entries.load(Ordering::SeqCst), 0);
+        state.take_pending_hooks().run();
+        assert_eq!(entries.load(Ordering::SeqCst), 2);
+        assert_eq!(errors.load(Ordering::SeqCst), 1);
+        assert_eq!(timeouts.load(Ordering::SeqCst), 1);
+        
+        // Removed hooks are no longer called
+        assert!(state.remove_hook(entry_hook));
+        let mut payload = LoggerPayload::new();
+        payload.error().message("write timeout".to_string()).update();
+        state.add_log(payload);
+        state.take_pending_hooks().run();
+        assert_eq!(entries.load(Ordering::SeqCst), 2);
+        assert_eq!(errors.load(Ordering::SeqCst), 2);
+        assert_eq!(timeouts.load(Ordering::SeqCst), 2);
+    }
+
+    #[test]
+    fn test_search_uses_rendered_text_and_caches_hits() {
+        let ctx = egui::Context::default();
+        let state = Dynamic::new(ReactiveEventLoggerState::new());
+        state.lock().pretty_json = true;
+        let logger = ReactiveEventLogger::new(&state);
+        logger.log_info(r#"{"disk": "sda",   "free": 0}"#);
+        logger.log_info("disk \x1b[31msda\x1b[0m removed");
+        
+        // JSON is searched as its compact preview, ANSI codes are skipped
+        let matcher = |query: &str| LogSearch { open: true, query: query.to_string(), ..LogSearch::default() }
+            .matcher().unwrap().unwrap();
+        assert_eq!(state.lock().search_hits(&matcher(r#""disk":"sda","free""#)), vec![1]);
+        assert!(state.lock().search_hits(&matcher(r#""sda",   "free""#)).is_empty());
+        assert_eq!(state.lock().search_hits(&matcher("sda")), vec![2, 1]);
+        
+        state.lock().search = LogSearch { open: true, query: "sda".to_string(), ..LogSearch::default() };
+        let cache_id = logger.id("logger_search_cache");
+        let run_frame = || {
+            let _ = ctx.run(egui::RawInput::default(), |ctx| {
+                egui::CentralPanel::default().show(ctx, |ui| logger.show(ui));
+            });
+        };
+        run_frame();
+        let cached = ctx.data(|data| data.get_temp::<SearchCache>(cache_id)).unwrap();
+        assert_eq!(*cached.hits, vec![2, 1]);
+        
+        // Unchanged results are reused, new entries are searched again
+        run_frame();
+        let reused = ctx.data(|data| data.get_temp::<SearchCache>(cache_id)).unwrap();
+        assert!(std::sync::Arc::ptr_eq(&cached.hits, &reused.hits));
+        logger.log_info("sda mounted");
+        run_frame();
+        let updated = ctx.data(|data| data.get_temp::<SearchCache>(cache_id)).unwrap();
+        assert_eq!(*updated.hits, vec![3, 2, 1]);
+    }
+
+    #[test]
+    fn test_hooks_can_log_to_the_same_state() {
+        use std::sync::{Arc, Mutex};
+        
+        let state = Dynamic::new(ReactiveEventLoggerState::new());
+        state.lock().deduplication = Deduplication::Window(std::time::Duration::from_secs(60));
+        let repeats = Arc::new(Mutex::new(Vec::new()));
+        let hook_state = state.clone();
+        let seen = repeats.clone();
+        state.lock().on_level(LogType::Error, move |log| {
+            seen.lock().unwrap().push(log.repeat_count);
+            ReactiveEventLogger::new(&hook_state).log_warning("error reported");
+        });
+        
+        // The hook runs after the lock is released, so logging from it doesn't deadlock
+        let logger = ReactiveEventLogger::new(&state);
+        logger.log_error("disk full");
+        logger.log_error("disk full");
+        assert_eq!(*repeats.lock().unwrap(), vec![1, 2]);
+        let state = state.lock();
+        let messages: Vec<&str> = state.logs.iter().map(|log| log.log_message.content.value.as_str()).collect();
+        assert_eq!(messages, vec!["disk full", "error reported"]);
+        assert_eq!(state.logs[1].repeat_count, 2);
+    }
+
+    #[test]
+    fn test_hook_queue_is_bounded_and_run_by_handles() {
+        use std::sync::{Arc, Mutex};
+        
+        let state = Dynamic::new(ReactiveEventLoggerState::new());
+        let seen = Arc::new(Mutex::new(Vec::new()));
+        let hook_seen = seen.clone();
+        state.lock().on_entry(move |log| hook_seen.lock().unwrap().push(log.level().to_string()));
+        
+        // Entries added directly keep only the newest calls until they are run
+        for i in 0..crate::hooks::MAX_PENDING_HOOKS + 5 {
+            let mut payload = LoggerPayload::new();
+            payload.info().message(format!("direct {}", i)).update();
+            state.lock().add_log(payload);
+        }
+        assert_eq!(state.lock().hooks.dropped(), 5);
+        let pending = state.lock().take_pending_hooks();
+        pending.run();
+        assert_eq!(seen.lock().unwrap().len(), crate::hooks::MAX_PENDING_HOOKS);
+        seen.lock().unwrap().clear();
+        
+        // Finishing a progress entry or closing a group runs the queued calls
+        let logger = ReactiveEventLogger::new(&state);
+        let progress = logger.progress("Copying", 10);
+        let mut payload = LoggerPayload::new();
+        payload.info().message("direct".to_string()).update();
+        state.lock().add_log(payload);
+        progress.finish_err("disk full");
+        assert_eq!(*seen.lock().unwrap(), ["PROGRESS", "INFO", "ERROR"]);
+        
+        let group = logger.group("Formatting");
+        let mut payload = LoggerPayload::new();
+        payload.warning().message("direct".to_string()).update();
+        state.lock().add_log(payload);
+        drop(group);
+        assert_eq!(seen.lock().unwrap().len(), 5);
+        assert!(state.lock().take_pending_hooks().is_empty());
+    }
+
+    #[test]
+    fn test_alert_toasts() {
+        let ctx = egui::Context::default();
+        let state = Dynamic::new(state_with_logs(2));
+        state.lock().add_alert_rule(AlertRule::matching("Disk", "disk (full|removed)").unwrap());
+        let logger = ReactiveEventLogger::new(&state);
+        logger.log_error("disk full");
+        
+        let alert = state.lock().alerts.raised[0].clone();
+        assert_eq!(alert.rule, "Disk");
+        assert_eq!(alert.sequence, state.lock().logs.last().unwrap().sequence);
+        
+        let toast_layer = egui::LayerId::new(egui::Order::Middle, logger.id("alert_toast").with(alert.id));
+        let run_frame = || {
+            let _ = ctx.run(egui::RawInput::default(), |ctx| {
+                logger.show_alert_toasts(ctx);
+            });
+        };
+        run_frame();
+        assert!(ctx.memory(|mem| mem.areas().is_visible(&toast_layer)));
+        
+        // The alert toast moves up above a save result toast
+        let alert_rect = || ctx.memory(|mem| mem.area_rect(toast_layer.id)).unwrap();
+        run_frame();
+        let alone = alert_rect();
+        ctx.data_mut(|data| data.insert_temp(logger.id("save_logs_success"), "Logs saved".to_string()));
+        for _ in 0..3 {
+            let _ = ctx.run(egui::RawInput::default(), |ctx| {
+                egui::CentralPanel::default().show(ctx, |ui| logger.show(ui));
+                logger.show_alert_toasts(ctx);
+            });
+        }
+        let saved = ctx.memory(|mem| mem.area_rect(logger.id("save_logs_success_toast"))).unwrap();
+        assert_eq!(saved.bottom(), alone.bottom());
+        assert!(alert_rect().bottom() < saved.top());
+        ctx.data_mut(|data| data.remove::<String>(logger.id("save_logs_success")));
+        
+        // A dismissed toast stays in the notification center
+        state.lock().alerts.dismiss(alert.id);
+        run_frame();
+        assert!(!ctx.memory(|mem| mem.areas().is_visible(&toast_layer)));
+        assert_eq!(state.lock().alerts.unseen_count(), 1);
+    }
+
+    #[test]
+    fn test_unread_counts() {
+        let ctx = egui::Context::default();
+        let state = Dynamic::new(ReactiveEventLoggerState::new());
+        let logger = ReactiveEventLogger::new(&state);
+        logger.log_info("started");
+        logger.log_warning("slow response");
+        logger.log_warning("slow response again");
+        logger.log_error("request failed");
+        logger.log_custom("network", "connected");
+        
+        assert_eq!(state.lock().unread_counts(), UnreadCounts { errors: 1, warnings: 2, info: 2, debug: 0 });
+        assert_eq!(logger.tab_title("Logger").text(), "Logger ⚠2 ✖1");
+        
+        // Showing the panel marks everything as read
+        let _ = ctx.run(egui::RawInput::default(), |ctx| {
+            egui::CentralPanel::default().show(ctx, |ui| logger.show(ui));
+        });
+        assert_eq!(state.lock().unread_counts().total(), 0);
+        assert_eq!(logger.tab_title("Logger").text(), "Logger");
+        
+        logger.log_error("request failed again");
+        assert_eq!(logger.tab_title("Logger").text(), "Logger ✖1");
+        
+        // A repeat merged into an already read row is unread again
+        state.lock().deduplication = Deduplication::Consecutive;
+        state.lock().mark_all_read();
+        logger.channel("jobs").log_error("backup failed");
+        state.lock().mark_all_read();
+        state.lock().mark_channel_read("jobs");
+        logger.channel("jobs").log_error("backup failed");
+        assert_eq!(state.lock().logs.last().unwrap().repeat_count, 2);
+        assert_eq!(logger.tab_title("Logger").text(), "Logger ✖1");
+        assert_eq!(state.lock().channel_unread("jobs"), (1, 1));
+    }
+
+    #[test]
+    fn test_run_command() {
+        let mut state = state_with_logs(2);
+        state.commands
+            .register("greet", |args, _| Ok(format!("Hello, {}!", args.join(" "))))
+            .help("greet <name>");
+        
+        state.run_command("  greet  SD card ");
+        state.run_command("greet SD card");
+        let messages: Vec<&str> = state.logs[2..].iter().map(|log| log.log_message.content.value.as_str()).collect();
+        assert_eq!(messages, ["> greet  SD card", "Hello, SD card!", "> greet SD card", "Hello, SD card!"]);
+        assert!(state.logs[2..].iter().all(|log| LogType::Command.matches(log)));
+        
+        // Unknown commands are reported as marked command entries, not errors
+        state.run_command("format");
+        let error = state.logs.last().unwrap();
+        assert!(LogType::Command.matches(error));
+        assert!(error.log_message.content.value.starts_with(COMMAND_ERROR_MARKER));
+        assert_eq!(state.unread_counts().errors, 0);
+        
+        // Built-in filter command
+        state.run_command("filter disk");
+        assert_eq!(state.filter.text_filter, "disk");
+        state.run_command("filter");
+        assert!(state.filter.text_filter.is_empty());
+        
+        assert_eq!(state.command_history, ["greet  SD card", "greet SD card", "format", "filter disk", "filter"]);
+        
+        state.run_command("clear");
+        assert!(state.logs.is_empty());
+    }
+
+    #[test]
+    fn test_session_keeps_command_and_progress_types() {
+        let mut state = ReactiveEventLoggerState::new();
+        state.run_command("help");
+        let mut payload = LoggerPayload::new();
+        payload.info().message("Wiping".to_string()).update();
+        state.start_progress(payload, 10);
+        
+        let path = std::env::temp_dir().join(format!("egui_lens_types_test_{}.json", std::process::id()));
+        state.save_session(&path).unwrap();
+        let mut restored = ReactiveEventLoggerState::new();
+        restored.load_session(&path).unwrap();
+        let _ = std::fs::remove_file(path);
+        
+        let levels: Vec<&str> = restored.logs.iter().map(LoggerPayload::level).collect();
+        assert_eq!(levels, ["COMMAND", "COMMAND", "PROGRESS"]);
+        assert!(LogType::Command.matches(&restored.logs[0]));
+        assert!(LogType::Progress.matches(&restored.logs[2]));
+    }
+
+    #[test]
+    fn test_command_line_input() {
+        let ctx = egui::Context::default();
+        let state = Dynamic::new(ReactiveEventLoggerState::new());
+        state.lock().apply_config(LoggerConfig::new().command_line(true));
+        let logger = ReactiveEventLogger::new(&state);
+        let key = |key| egui::Event::Key { key, physical_key: None, pressed: true, repeat: false, modifiers: egui::Modifiers::NONE };
+        let run_frame = |events: Vec<egui::Event>| {
+            let input = egui::RawInput { events, ..Default::default() };
+            let _ = ctx.run(input, |ctx| {
+                egui::CentralPanel::default().show(ctx, |ui| logger.show(ui));
+            });
+        };
+        
+        run_frame(Vec::new());
+        ctx.memory_mut(|mem| mem.request_focus(logger.id("logger_command_field")));
+        run_frame(Vec::new());
+        
+        // Tab completes the command name
+        run_frame(vec![egui::Event::Text("fil".to_string())]);
+        run_frame(vec![key(egui::Key::Tab)]);
+        let input = ctx.data_mut(|data| data.get_temp::<String>(logger.id("logger_command_input")));
+        assert_eq!(input.as_deref(), Some("filter "));
+        
+        // Enter runs it
+        run_frame(vec![egui::Event::Text("disk".to_string())]);
+        run_frame(vec![key(egui::Key::Enter)]);
+        assert_eq!(state.lock().filter.text_filter, "disk");
+        assert_eq!(state.lock().command_history, ["filter disk"]);
+        
+        // The filter modal loads it rather than the filter saved before
+        let mut saved = LogFilter::default();
+        saved.load_from_memory_with_id(&ctx, logger.id_source);
+        assert_eq!(saved.text_filter, "disk");
+        
+        // Up recalls it
+        run_frame(vec![key(egui::Key::ArrowUp)]);
+        let input = ctx.data_mut(|data| data.get_temp::<String>(logger.id("logger_command_input")));
+        assert_eq!(input.as_deref(), Some("filter disk"));
+    }
+
+    #[test]
+    fn test_rate_limited_add_log() {
+        let mut state = ReactiveEventLoggerState::new();
+        state.rate_limiter.add_limit(crate::RateLimit::level(LogType::Debug, 2, 0.0));
+        for i in 0..5 {
+            let mut payload = LoggerPayload::new();
+            payload.debug().message(format!("poll {}", i)).update();
+            state.add_log(payload);
+        }
+        let mut payload = LoggerPayload::new();
+        payload.info().message("not limited".to_string()).update();
+        state.add_log(payload);
+        
+        assert_eq!(state.log_count(), 3);
+        assert_eq!(state.rate_limiter.metrics().suppressed, 3);
+        
+        state.rate_limiter.summary_interval = std::time::Duration::ZERO;
+        state.flush_rate_limit_summaries();
+        let summary = state.logs.last().unwrap();
+        assert_eq!(summary.level(), "WARNING");
+        assert!(summary.log_message.content.value.starts_with("suppressed 3 DEBUG messages"));
+    }
+
+    #[test]
+    fn test_deduplication() {
+        let mut state = ReactiveEventLoggerState::new();
+        let log = |level: &str, message: &str| {
+            let mut payload = LoggerPayload::new();
+            match level {
+                "warning" => payload.warning(),
+                _ => payload.info(),
+            };
+            payload.message(message.to_string()).update();
+            payload.clone()
+        };
+        
+        state.add_log(log("info", "retrying"));
+        state.add_log(log("info", "retrying"));
+        assert_eq!(state.log_count(), 2, "deduplication is off by default");
+        
+        state.clear_logs();
+        state.deduplication = Deduplication::Consecutive;
+        state.add_log(log("info", "retrying"));
+        state.add_log(log("info", "retrying"));
+        state.add_log(log("warning", "retrying"));
+        state.add_log(log("info", "retrying"));
+        assert_eq!(state.logs.iter().map(|l| l.repeat_count).collect::<Vec<_>>(), vec![2, 1, 1]);
+        
+        state.clear_logs();
+        state.deduplication = Deduplication::Window(std::time::Duration::from_secs(60));
+        state.add_log(log("info", "retrying"));
+        state.add_log(log("info", "connected"));
+        state.add_log(log("info", "retrying"));
+        assert_eq!(state.logs.iter().map(|l| l.repeat_count).collect::<Vec<_>>(), vec![2, 1]);
+        assert!(format_log_line(&state.logs[0]).contains("(repeated 2 times"));
+    }
+
+    #[test]
+    fn test_message_pattern() {
+        assert_eq!(message_pattern("Retry 3 of 10"), message_pattern("Retry 12 of 10"));
+        assert_eq!(message_pattern("\u{1b}[31mport 8080\u{1b}[0m"), "port #");
+        
+        let mut filter = LogFilter::default();
+        filter.exclude_like("Retry 3 of 10");
+        let mut payload = LoggerPayload::new();
+        payload.info().message("Retry 7 of 10".to_string()).update();
+        assert!(!filter.should_display(&payload));
+    }
+}
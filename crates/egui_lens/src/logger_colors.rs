@@ -55,6 +55,20 @@ pub struct LogColors {
     #[serde(with = "color32_serde")]
     pub default: Color32,
     
+    // Command line entries (defaulted so older color files still load)
+    #[serde(with = "color32_serde", default = "default_command")]
+    pub command: Color32,
+    
+    // JSON syntax colors (defaulted so older color files still load)
+    #[serde(with = "color32_serde", default = "default_json_key")]
+    pub json_key: Color32,
+    #[serde(with = "color32_serde", default = "default_json_string")]
+    pub json_string: Color32,
+    #[serde(with = "color32_serde", default = "default_json_number")]
+    pub json_number: Color32,
+    #[serde(with = "color32_serde", default = "default_json_literal")]
+    pub json_literal: Color32,
+    
     // Flexible custom colors - map from identifier string to color
     #[serde(default)]
     pub custom_colors: HashMap<String, Color32Wrapper>,
@@ -137,6 +151,13 @@ impl Default for LogColors {
             progress: Color32::from_rgb(100, 255, 200),  // Cyan
             success: Color32::from_rgb(100, 255, 100),   // Bright green
             default: Color32::from_rgb(255, 255, 255),   // White
+            command: default_command(),
+            
+            // JSON syntax colors
+            json_key: default_json_key(),
+            json_string: default_json_string(),
+            json_number: default_json_number(),
+            json_literal: default_json_literal(),
             
             // Custom colors via HashMap
             custom_colors,
@@ -144,6 +165,28 @@ impl Default for LogColors {
     }
 }
 
+// Default color of command line entries
+fn default_command() -> Color32 {
+    Color32::from_rgb(255, 210, 120)     // Amber
+}
+
+// Default JSON syntax colors
+fn default_json_key() -> Color32 {
+    Color32::from_rgb(156, 220, 254)     // Light blue
+}
+
+fn default_json_string() -> Color32 {
+    Color32::from_rgb(206, 145, 120)     // Salmon
+}
+
+fn default_json_number() -> Color32 {
+    Color32::from_rgb(181, 206, 168)     // Pale green
+}
+
+fn default_json_literal() -> Color32 {
+    Color32::from_rgb(86, 156, 214)      // Blue (true/false/null)
+}
+
 // Module for serializing and deserializing Color32
 /// Helper methods for custom log types colors
 impl LogColors {
@@ -0,0 +1,203 @@
+//! Memory budget of the log buffer.
+//!
+//! `max_logs` counts entries regardless of their size. A `MemoryBudget`
+//! additionally limits the approximate heap size of the stored entries:
+//! oversized messages are truncated (their full text is written to an
+//! archive file that can be expanded from the detail pane) and the oldest
+//! entries are evicted while the budget is exceeded.
+//!
+//! Archive files are written, read and deleted by a background thread, in
+//! the order the operations were requested, so no file I/O happens while
+//! the logger state is locked. The file of an entry is deleted when the
+//! entry is evicted or cleared.
+use std::path::{Path, PathBuf};
+use std::sync::OnceLock;
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::sync::mpsc;
+
+use crate::payload::LoggerPayload;
+
+/// Marker appended to truncated messages
+pub const TRUNCATION_MARKER: &str = "… [truncated, expand from archive]";
+
+/// Counter used to give archive files unique names within the process
+static ARCHIVE_COUNTER: AtomicU64 = AtomicU64::new(0);
+
+/// MemoryBudget
+///
+/// Limits the approximate heap size of the stored log entries.
+#[derive(Clone, Debug, PartialEq)]
+pub struct MemoryBudget {
+    /// Total budget for all stored entries, in bytes
+    pub max_bytes: usize,
+    /// Messages longer than this are truncated, in bytes
+    pub max_message_bytes: usize,
+    /// Directory the full text of truncated messages is written to.
+    /// With `None` truncated text is discarded.
+    pub archive_dir: Option<PathBuf>,
+}
+
+impl MemoryBudget {
+    /// Create a budget of `max_bytes`, truncating messages above 1/64 of
+    /// the budget and archiving them in the system temp directory
+    pub fn new(max_bytes: usize) -> Self {
+        Self {
+            max_bytes,
+            max_message_bytes: (max_bytes / 64).max(1024),
+            archive_dir: Some(std::env::temp_dir().join("egui_lens_archive")),
+        }
+    }
+
+    /// Create a budget of `megabytes` MB
+    pub fn megabytes(megabytes: f64) -> Self {
+        Self::new((megabytes * MEGABYTE) as usize)
+    }
+
+    /// Set the size above which messages are truncated
+    pub fn max_message_bytes(mut self, max_message_bytes: usize) -> Self {
+        self.max_message_bytes = max_message_bytes;
+        self
+    }
+
+    /// Set the archive directory, or disable archiving with `None`
+    pub fn archive_dir(mut self, archive_dir: Option<PathBuf>) -> Self {
+        self.archive_dir = archive_dir;
+        self
+    }
+
+    /// Truncate the message of an entry if it exceeds `max_message_bytes`.
+    ///
+    /// The full message is handed to the archive thread, which writes it
+    /// to the archive directory. If writing fails, expanding the message
+    /// from the detail pane shows the error.
+    pub fn truncate_message(&self, log: &mut LoggerPayload) {
+        let message = &log.log_message.content.value;
+        if message.len() <= self.max_message_bytes {
+            return;
+        }
+
+        log.archived = self.archive_dir
+            .as_deref()
+            .map(|dir| write_archive(dir, message));
+
+        // Cut at a character boundary
+        let mut cut = self.max_message_bytes;
+        while !message.is_char_boundary(cut) {
+            cut -= 1;
+        }
+        let mut truncated = message[..cut].to_string();
+        truncated.push_str(TRUNCATION_MARKER);
+        log.log_message.content.value = truncated;
+    }
+}
+
+/// File operation on the archive, done by the archive thread
+enum ArchiveTask {
+    /// Write a message to a new file
+    Write(PathBuf, String),
+    /// Read a file back
+    Read(PathBuf, mpsc::Sender<std::io::Result<String>>),
+    /// Delete a file
+    Remove(PathBuf),
+}
+
+/// Queue of the archive thread, started on first use
+fn archive_thread() -> &'static mpsc::Sender<ArchiveTask> {
+    static QUEUE: OnceLock<mpsc::Sender<ArchiveTask>> = OnceLock::new();
+    QUEUE.get_or_init(|| {
+        let (sender, receiver) = mpsc::channel();
+        std::thread::spawn(move || {
+            for task in receiver {
+                match task {
+                    ArchiveTask::Write(path, message) => {
+                        let written = path.parent()
+                            .map_or(Ok(()), std::fs::create_dir_all)
+                            .and_then(|_| std::fs::write(&path, message));
+                        if let Err(e) = written {
+                            eprintln!("Failed to archive message to {}: {}", path.display(), e);
+                        }
+                    }
+                    ArchiveTask::Read(path, reply) => {
+                        let _ = reply.send(std::fs::read_to_string(path));
+                    }
+                    ArchiveTask::Remove(path) => {
+                        let _ = std::fs::remove_file(path);
+                    }
+                }
+            }
+        });
+        sender
+    })
+}
+
+/// Queue a message to be written to a new file in the archive directory,
+/// returning the path of the file
+fn write_archive(dir: &Path, message: &str) -> PathBuf {
+    let path = dir.join(format!(
+        "{}-{}.log",
+        std::process::id(),
+        ARCHIVE_COUNTER.fetch_add(1, Ordering::Relaxed)
+    ));
+    let _ = archive_thread().send(ArchiveTask::Write(path.clone(), message.to_string()));
+    path
+}
+
+/// Queue the archive file of an evicted entry to be deleted
+pub(crate) fn remove_archive(path: &Path) {
+    let _ = archive_thread().send(ArchiveTask::Remove(path.to_path_buf()));
+}
+
+/// Read the full text of a truncated message back from the archive.
+///
+/// The read waits for the archive writes requested before it.
+pub fn read_archive(path: &Path) -> std::io::Result<String> {
+    let (reply, result) = mpsc::channel();
+    if archive_thread().send(ArchiveTask::Read(path.to_path_buf(), reply)).is_err() {
+        return std::fs::read_to_string(path);
+    }
+    result.recv().unwrap_or_else(|_| std::fs::read_to_string(path))
+}
+
+const MEGABYTE: f64 = 1024.0 * 1024.0;
+
+/// Format a byte count in megabytes with one decimal ("12.3")
+pub fn format_megabytes(bytes: usize) -> String {
+    let megabytes = bytes as f64 / MEGABYTE;
+    if megabytes.fract() == 0.0 {
+        format!("{}", megabytes)
+    } else {
+        format!("{:.1}", megabytes)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_truncate_message() {
+        let dir = std::env::temp_dir().join(format!("egui_lens_archive_test_{}", std::process::id()));
+        let budget = MemoryBudget::new(1 << 20)
+            .max_message_bytes(10)
+            .archive_dir(Some(dir.clone()));
+
+        let mut log = LoggerPayload::new();
+        log.info().message("äbcdefghijklmnop".to_string()).update();
+        budget.truncate_message(&mut log);
+
+        assert_eq!(log.log_message.content.value, format!("äbcdefghi{}", TRUNCATION_MARKER));
+        let archived = log.archived.as_ref().expect("message is archived");
+        assert_eq!(read_archive(archived).unwrap(), "äbcdefghijklmnop");
+
+        remove_archive(archived);
+        assert!(read_archive(archived).is_err());
+
+        let _ = std::fs::remove_dir_all(dir);
+    }
+
+    #[test]
+    fn test_format_megabytes() {
+        assert_eq!(format_megabytes(64 * 1024 * 1024), "64");
+        assert_eq!(format_megabytes(12_897_485), "12.3");
+    }
+}
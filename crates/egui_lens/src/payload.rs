@@ -13,6 +13,32 @@ pub struct LoggerPayload {
     pub timestamp: TimestampContainer,
     pub log_level: LogLevelContainer,
     pub log_message: MessageContainer,
+    /// Sequence number assigned by the logger state when the entry is stored.
+    /// It uniquely identifies the entry for the lifetime of the state.
+    pub sequence: u64,
+    /// Precise creation time of the entry
+    pub created_at: DateTime<Local>,
+    /// Source location of the code that logged the entry, if known
+    pub location: Option<&'static std::panic::Location<'static>>,
+    /// Number of identical entries merged into this one by deduplication
+    pub repeat_count: u32,
+    /// Creation time of the most recent merged duplicate
+    pub last_seen_at: DateTime<Local>,
+    /// Archive file holding the full text of a truncated message
+    pub archived: Option<std::path::PathBuf>,
+    /// Marked by the user as interesting
+    pub bookmarked: bool,
+    /// Free-text note attached by the user
+    pub note: Option<String>,
+    /// Progress of a running operation, while the entry is live
+    pub progress: Option<crate::progress::Progress>,
+    /// Header entries of the operation groups this entry is nested in,
+    /// outermost first
+    pub groups: Vec<u64>,
+    /// Set on the header entry of an operation group
+    pub group: Option<crate::group::LogGroup>,
+    /// Named channel (stream) the entry belongs to, if any
+    pub channel: Option<String>,
 }
 
 /// TimestampContainer
@@ -96,6 +122,18 @@ impl LoggerPayload {
                     color: egui::Color32::WHITE,
                 },
             },
+            sequence: 0,
+            created_at: Local::now(),
+            location: None,
+            repeat_count: 1,
+            last_seen_at: Local::now(),
+            archived: None,
+            bookmarked: false,
+            note: None,
+            progress: None,
+            groups: Vec::new(),
+            group: None,
+            channel: None,
         }
     }
     
@@ -157,6 +195,14 @@ impl LoggerPayload {
         self
     }
 
+    /// Set a level kept in the info slot that is neither INFO nor a custom
+    /// type, like COMMAND or PROGRESS
+    pub(crate) fn info_slot_level(&mut self, level: &str) -> &mut Self {
+        self.info();
+        self.log_level.info.value = level.to_string();
+        self
+    }
+
     /// Set message content
     pub fn message(&mut self, content: String) -> &mut Self {
         self.log_message.content.value = content;
@@ -206,6 +252,116 @@ impl LoggerPayload {
         self
     }
 
+    /// Get the text of the active log level ("INFO", "CUSTOM:network", ...)
+    /// or an empty string for message-only entries
+    pub fn level(&self) -> &str {
+        if !self.log_level.info.value.is_empty() {
+            &self.log_level.info.value
+        } else if !self.log_level.debug.value.is_empty() {
+            &self.log_level.debug.value
+        } else if !self.log_level.warning.value.is_empty() {
+            &self.log_level.warning.value
+        } else {
+            &self.log_level.error.value
+        }
+    }
+
+    /// Get the precise creation time formatted with microseconds and UTC offset
+    pub fn precise_timestamp(&self) -> String {
+        self.created_at.format("%Y-%m-%d %H:%M:%S%.6f %:z").to_string()
+    }
+    
+    /// Get the source location as "file:line:column", if known
+    pub fn location_text(&self) -> Option<String> {
+        self.location.map(|location| location.to_string())
+    }
+
+    /// Convert the entry into a JSON object
+    pub fn to_json(&self) -> serde_json::Value {
+        serde_json::json!({
+            "sequence": self.sequence,
+            "timestamp": self.timestamp.value.value,
+            "time": self.created_at.to_rfc3339(),
+            "level": self.level(),
+            "message": self.log_message.content.value,
+            "location": self.location_text(),
+            "repeat_count": self.repeat_count,
+            "last_time": self.last_seen_at.to_rfc3339(),
+            "bookmarked": self.bookmarked,
+            "note": self.note,
+            "groups": self.groups,
+            "channel": self.channel,
+            "progress": self.progress.as_ref().map(|progress| serde_json::json!({
+                "current": progress.current,
+                "total": progress.total,
+            })),
+        })
+    }
+    
+    /// Restore an entry from the JSON object created by `to_json`.
+    ///
+    /// The source location cannot be restored, since it refers to code
+    /// of the process that logged the entry.
+    pub fn from_json(value: &serde_json::Value) -> Option<Self> {
+        let time = |key: &str| {
+            value.get(key)
+                .and_then(|time| time.as_str())
+                .and_then(|time| DateTime::parse_from_rfc3339(time).ok())
+                .map(|time| time.with_timezone(&Local))
+        };
+        
+        let mut payload = Self::new();
+        match value.get("level")?.as_str()? {
+            "" => payload.as_message_only(),
+            "INFO" => payload.info(),
+            "DEBUG" => payload.debug(),
+            "WARNING" => payload.warning(),
+            "ERROR" => payload.error(),
+            level @ ("COMMAND" | "PROGRESS") => payload.info_slot_level(level),
+            level => payload.custom_type(level.strip_prefix("CUSTOM:").unwrap_or(level)),
+        };
+        payload.message(value.get("message")?.as_str()?.to_string());
+        payload.timestamp.value.value = value.get("timestamp")?.as_str()?.to_string();
+        payload.sequence = value.get("sequence").and_then(|sequence| sequence.as_u64()).unwrap_or(0);
+        payload.created_at = time("time").unwrap_or(payload.created_at);
+        payload.last_seen_at = time("last_time").unwrap_or(payload.created_at);
+        payload.repeat_count = value.get("repeat_count")
+            .and_then(|count| count.as_u64())
+            .map_or(1, |count| count as u32);
+        payload.bookmarked = value.get("bookmarked").and_then(|bookmarked| bookmarked.as_bool()).unwrap_or(false);
+        payload.note = value.get("note").and_then(|note| note.as_str()).map(str::to_string);
+        payload.channel = value.get("channel").and_then(|channel| channel.as_str()).map(str::to_string);
+        payload.groups = value.get("groups")
+            .and_then(|groups| groups.as_array())
+            .map(|groups| groups.iter().filter_map(|group| group.as_u64()).collect())
+            .unwrap_or_default();
+        Some(payload)
+    }
+    
+    /// Approximate heap size of the entry in bytes, including the struct itself
+    pub fn heap_size(&self) -> usize {
+        std::mem::size_of::<Self>()
+            + self.timestamp.value.value.capacity()
+            + self.log_level.info.value.capacity()
+            + self.log_level.debug.value.capacity()
+            + self.log_level.warning.value.capacity()
+            + self.log_level.error.value.capacity()
+            + self.log_message.content.value.capacity()
+            + self.archived.as_ref().map_or(0, |path| path.as_os_str().len())
+            + self.groups.capacity() * std::mem::size_of::<u64>()
+            + self.channel.as_ref().map_or(0, |channel| channel.capacity())
+            + self.note.as_ref().map_or(0, |note| note.capacity())
+    }
+    
+    /// Returns true if the other entry has the same level and message
+    /// and belongs to the same channel and operation groups
+    pub fn is_duplicate_of(&self, other: &LoggerPayload) -> bool {
+        self.level() == other.level()
+            && self.log_message.content.value == other.log_message.content.value
+            && self.channel == other.channel
+            && self.groups == other.groups
+    }
+
     /// Update timestamp to current time and finalize
     pub fn update(&mut self) -> &mut Self {
         // Only add timestamp if it's not already set and this isn't a message-only log
@@ -216,6 +372,8 @@ impl LoggerPayload {
             !self.log_level.error.value.is_empty()) {
             let local: DateTime<Local> = Local::now();
             self.timestamp.value.value = local.format("%Y-%m-%d %H:%M:%S").to_string();
+            self.created_at = local;
+            self.last_seen_at = local;
         }
         self
     }
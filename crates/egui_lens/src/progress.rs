@@ -0,0 +1,196 @@
+//! Updatable progress entries.
+//!
+//! `ReactiveEventLogger::progress` stores a single live entry for a
+//! long-running operation and returns a `ProgressHandle`. Updates through
+//! the handle change that entry in place, and the table renders it as an
+//! inline progress bar with an ETA. Finishing the handle turns the entry
+//! into a normal log line ("Formatting: done (1,024 in 12s)").
+use std::time::{Duration, Instant};
+
+use egui_mobius_reactive::{Dynamic, ReactiveWidgetRef};
+
+use crate::logger::ReactiveEventLoggerState;
+use crate::rate_limit::format_count;
+
+/// Progress
+///
+/// Progress of a running operation, attached to its live log entry.
+#[derive(Clone, Debug, PartialEq)]
+pub struct Progress {
+    /// Name of the operation
+    pub label: String,
+    /// Units of work done so far
+    pub current: u64,
+    /// Total units of work (0 if unknown)
+    pub total: u64,
+    /// When the operation started, used for the ETA
+    pub started_at: Instant,
+}
+
+impl Progress {
+    /// Start tracking an operation of `total` units
+    pub fn new(label: &str, total: u64) -> Self {
+        Self {
+            label: label.to_string(),
+            current: 0,
+            total,
+            started_at: Instant::now(),
+        }
+    }
+
+    /// Completed fraction between 0 and 1
+    pub fn fraction(&self) -> f32 {
+        if self.total == 0 {
+            0.0
+        } else {
+            self.current.min(self.total) as f32 / self.total as f32
+        }
+    }
+
+    /// Estimated time remaining, extrapolated from the rate so far
+    pub fn eta(&self, now: Instant) -> Option<Duration> {
+        if self.current == 0 || self.total == 0 {
+            return None;
+        }
+        let remaining = self.total.saturating_sub(self.current);
+        let elapsed = now.saturating_duration_since(self.started_at);
+        Some(elapsed.mul_f64(remaining as f64 / self.current as f64))
+    }
+
+    /// Status shown on the progress bar ("512 / 1,024 (50%) ETA 12s")
+    pub fn status_text(&self, now: Instant) -> String {
+        if self.total == 0 {
+            return format_count(self.current);
+        }
+
+        let mut text = format!(
+            "{} / {} ({:.0}%)",
+            format_count(self.current),
+            format_count(self.total),
+            self.fraction() * 100.0
+        );
+        if let Some(eta) = self.eta(now) {
+            text.push_str(&format!(" ETA {}", format_duration(eta)));
+        }
+        text
+    }
+
+    /// Message of the finished entry
+    pub fn finished_message(&self, error: Option<&str>, now: Instant) -> String {
+        let elapsed = format_duration(now.saturating_duration_since(self.started_at));
+        match error {
+            None => format!("{}: done ({} in {})", self.label, format_count(self.current.max(self.total)), elapsed),
+            Some(error) => format!(
+                "{}: failed at {:.0}% after {}: {}",
+                self.label,
+                self.fraction() * 100.0,
+                elapsed,
+                error
+            ),
+        }
+    }
+}
+
+/// ProgressHandle
+///
+/// Handle to a live progress entry, returned by `ReactiveEventLogger::progress`.
+/// It can be moved to the thread doing the work. Dropping the handle
+/// without finishing it marks the operation as failed.
+pub struct ProgressHandle {
+    state: Dynamic<ReactiveEventLoggerState>,
+    sequence: u64,
+    finished: bool,
+}
+
+impl ProgressHandle {
+    pub(crate) fn new(state: Dynamic<ReactiveEventLoggerState>, sequence: u64) -> Self {
+        Self {
+            state,
+            sequence,
+            finished: false,
+        }
+    }
+
+    /// Sequence number of the live entry
+    pub fn sequence(&self) -> u64 {
+        self.sequence
+    }
+
+    /// Set the units of work done so far
+    pub fn set(&self, current: u64) {
+        if let Some(arc) = ReactiveWidgetRef::from_dynamic(&self.state).weak_ref.upgrade() {
+            arc.lock().unwrap().set_progress(self.sequence, current);
+        }
+    }
+
+    /// Finish the operation successfully
+    pub fn finish_ok(mut self) {
+        self.finish(None);
+    }
+
+    /// Finish the operation with an error
+    pub fn finish_err(mut self, error: &str) {
+        self.finish(Some(error));
+    }
+
+    fn finish(&mut self, error: Option<&str>) {
+        self.finished = true;
+        if let Some(arc) = ReactiveWidgetRef::from_dynamic(&self.state).weak_ref.upgrade() {
+            let hooks = {
+                let mut state = arc.lock().unwrap();
+                state.finish_progress(self.sequence, error);
+                state.take_pending_hooks()
+            };
+            hooks.run();
+        }
+    }
+}
+
+impl Drop for ProgressHandle {
+    fn drop(&mut self) {
+        if !self.finished {
+            self.finish(Some("stopped before completion"));
+        }
+    }
+}
+
+/// Format a duration for display ("12s", "3m 05s", "1h 02m")
+pub fn format_duration(duration: Duration) -> String {
+    let seconds = duration.as_secs();
+    if seconds < 60 {
+        format!("{}s", seconds)
+    } else if seconds < 3600 {
+        format!("{}m {:02}s", seconds / 60, seconds % 60)
+    } else {
+        format!("{}h {:02}m", seconds / 3600, seconds / 60 % 60)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_progress_text() {
+        let now = Instant::now();
+        let progress = Progress {
+            label: "Formatting".to_string(),
+            current: 512,
+            total: 2048,
+            started_at: now - Duration::from_secs(4),
+        };
+
+        assert_eq!(progress.fraction(), 0.25);
+        assert_eq!(progress.eta(now), Some(Duration::from_secs(12)));
+        assert_eq!(progress.status_text(now), "512 / 2,048 (25%) ETA 12s");
+        assert_eq!(progress.finished_message(None, now), "Formatting: done (2,048 in 4s)");
+        assert_eq!(progress.finished_message(Some("device busy"), now), "Formatting: failed at 25% after 4s: device busy");
+    }
+
+    #[test]
+    fn test_format_duration() {
+        assert_eq!(format_duration(Duration::from_secs(9)), "9s");
+        assert_eq!(format_duration(Duration::from_secs(185)), "3m 05s");
+        assert_eq!(format_duration(Duration::from_secs(3720)), "1h 02m");
+    }
+}
@@ -0,0 +1,283 @@
+//! Rate limiting of log ingestion.
+//!
+//! A misbehaving producer can push thousands of entries per second into the
+//! logger. The `RateLimiter` applies token-bucket limits to entries matching
+//! a `RateLimitScope` (a log level, a custom type or a source file). Entries
+//! exceeding the limit are dropped and counted; the logger state periodically
+//! turns these counts into summary entries such as
+//! "suppressed 4,210 network messages in 5s".
+use std::time::{Duration, Instant};
+
+use crate::logger::LogType;
+use crate::payload::LoggerPayload;
+
+/// RateLimitScope
+///
+/// Selects the log entries a rate limit applies to.
+#[derive(Clone, Debug, PartialEq)]
+pub enum RateLimitScope {
+    /// Entries of a log level or custom type, e.g. `LogType::Debug`
+    /// or `LogType::Custom("network".into())`
+    Level(LogType),
+    /// Entries logged from a source file whose path starts with the prefix,
+    /// e.g. "src/network/" (requires the entry's source location)
+    Source(String),
+}
+
+impl RateLimitScope {
+    /// Check if a log entry falls under this scope
+    pub fn matches(&self, log: &LoggerPayload) -> bool {
+        match self {
+            RateLimitScope::Level(log_type) => log_type.matches(log),
+            RateLimitScope::Source(prefix) => log
+                .location
+                .is_some_and(|location| location.file().starts_with(prefix.as_str())),
+        }
+    }
+
+    /// Human readable description used in summary entries
+    pub fn describe(&self) -> String {
+        match self {
+            RateLimitScope::Level(LogType::Custom(identifier)) => identifier.clone(),
+            RateLimitScope::Level(log_type) => format!("{:?}", log_type),
+            RateLimitScope::Source(prefix) => format!("{}*", prefix),
+        }
+    }
+}
+
+/// RateLimitMetrics
+///
+/// Counters of admitted and dropped entries.
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub struct RateLimitMetrics {
+    /// Entries that passed the limit
+    pub admitted: u64,
+    /// Entries dropped because the limit was exceeded
+    pub suppressed: u64,
+}
+
+/// RateLimit
+///
+/// A token-bucket limit for one scope. The bucket holds up to `burst`
+/// tokens and refills at `per_second` tokens per second; every admitted
+/// entry takes one token.
+#[derive(Clone, Debug)]
+pub struct RateLimit {
+    pub scope: RateLimitScope,
+    pub burst: u32,
+    pub per_second: f64,
+    pub metrics: RateLimitMetrics,
+    tokens: f64,
+    last_refill: Option<Instant>,
+    pending_suppressed: u64,
+    suppressed_since: Option<Instant>,
+}
+
+impl RateLimit {
+    /// Create a new rate limit for the scope
+    pub fn new(scope: RateLimitScope, burst: u32, per_second: f64) -> Self {
+        Self {
+            scope,
+            burst,
+            per_second,
+            metrics: RateLimitMetrics::default(),
+            tokens: burst as f64,
+            last_refill: None,
+            pending_suppressed: 0,
+            suppressed_since: None,
+        }
+    }
+
+    /// Rate limit for a log level or custom type
+    pub fn level(log_type: LogType, burst: u32, per_second: f64) -> Self {
+        Self::new(RateLimitScope::Level(log_type), burst, per_second)
+    }
+
+    /// Rate limit for a custom log type identifier
+    pub fn custom_type(identifier: &str, burst: u32, per_second: f64) -> Self {
+        Self::level(LogType::Custom(identifier.to_string()), burst, per_second)
+    }
+
+    /// Rate limit for entries logged from source files starting with the prefix
+    pub fn source(prefix: &str, burst: u32, per_second: f64) -> Self {
+        Self::new(RateLimitScope::Source(prefix.to_string()), burst, per_second)
+    }
+
+    /// Add the tokens accumulated since the last refill
+    fn refill(&mut self, now: Instant) {
+        if let Some(last) = self.last_refill {
+            let elapsed = now.saturating_duration_since(last).as_secs_f64();
+            self.tokens = (self.tokens + elapsed * self.per_second).min(self.burst as f64);
+        }
+        self.last_refill = Some(now);
+    }
+}
+
+/// RateLimiter
+///
+/// The set of rate limits applied by `ReactiveEventLoggerState::add_log`.
+#[derive(Clone, Debug)]
+pub struct RateLimiter {
+    /// Configured limits, checked in order
+    pub limits: Vec<RateLimit>,
+    /// How often a summary entry is produced while entries are suppressed
+    pub summary_interval: Duration,
+}
+
+impl Default for RateLimiter {
+    fn default() -> Self {
+        Self {
+            limits: Vec::new(),
+            summary_interval: Duration::from_secs(5),
+        }
+    }
+}
+
+impl RateLimiter {
+    /// Add a rate limit
+    pub fn add_limit(&mut self, limit: RateLimit) {
+        self.limits.push(limit);
+    }
+
+    /// Remove all rate limits (and their metrics)
+    pub fn clear_limits(&mut self) {
+        self.limits.clear();
+    }
+
+    /// Decide whether a log entry may be stored.
+    ///
+    /// An entry must find a token in every matching bucket; if one of them
+    /// is empty the entry is dropped and counted against that limit.
+    pub fn admit(&mut self, log: &LoggerPayload, now: Instant) -> bool {
+        let matching: Vec<usize> = self.limits
+            .iter()
+            .enumerate()
+            .filter(|(_, limit)| limit.scope.matches(log))
+            .map(|(index, _)| index)
+            .collect();
+
+        for &index in &matching {
+            self.limits[index].refill(now);
+        }
+
+        if let Some(&empty) = matching.iter().find(|&&index| self.limits[index].tokens < 1.0) {
+            let limit = &mut self.limits[empty];
+            limit.metrics.suppressed += 1;
+            limit.pending_suppressed += 1;
+            limit.suppressed_since.get_or_insert(now);
+            return false;
+        }
+
+        for &index in &matching {
+            let limit = &mut self.limits[index];
+            limit.tokens -= 1.0;
+            limit.metrics.admitted += 1;
+        }
+        true
+    }
+
+    /// Collect summary messages for limits that have been suppressing
+    /// entries for at least the summary interval
+    pub fn take_summaries(&mut self, now: Instant) -> Vec<String> {
+        let interval = self.summary_interval;
+        self.limits
+            .iter_mut()
+            .filter_map(|limit| {
+                let since = limit.suppressed_since?;
+                let elapsed = now.saturating_duration_since(since);
+                if limit.pending_suppressed == 0 || elapsed < interval {
+                    return None;
+                }
+
+                let summary = format!(
+                    "suppressed {} {} messages in {}s",
+                    format_count(limit.pending_suppressed),
+                    limit.scope.describe(),
+                    elapsed.as_secs().max(1),
+                );
+                limit.pending_suppressed = 0;
+                limit.suppressed_since = None;
+                Some(summary)
+            })
+            .collect()
+    }
+
+    /// Returns true if some limit has dropped entries not yet summarized
+    pub fn has_pending(&self) -> bool {
+        self.limits.iter().any(|limit| limit.pending_suppressed > 0)
+    }
+
+    /// Totals over all limits
+    pub fn metrics(&self) -> RateLimitMetrics {
+        self.limits.iter().fold(RateLimitMetrics::default(), |mut total, limit| {
+            total.admitted += limit.metrics.admitted;
+            total.suppressed += limit.metrics.suppressed;
+            total
+        })
+    }
+}
+
+/// Format a count with thousands separators ("4,210")
+pub fn format_count(count: u64) -> String {
+    let digits = count.to_string();
+    let mut formatted = String::new();
+    for (index, digit) in digits.chars().enumerate() {
+        if index > 0 && (digits.len() - index).is_multiple_of(3) {
+            formatted.push(',');
+        }
+        formatted.push(digit);
+    }
+    formatted
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn custom_log(identifier: &str) -> LoggerPayload {
+        let mut payload = LoggerPayload::with_custom_type(identifier);
+        payload.message("packet".to_string()).update();
+        payload
+    }
+
+    #[test]
+    fn test_token_bucket() {
+        let mut limiter = RateLimiter::default();
+        limiter.add_limit(RateLimit::custom_type("network", 3, 1.0));
+        let start = Instant::now();
+
+        let admitted = (0..10).filter(|_| limiter.admit(&custom_log("network"), start)).count();
+        assert_eq!(admitted, 3);
+        assert!(limiter.admit(&custom_log("database"), start), "other types are not limited");
+
+        // Two seconds later two tokens have been refilled
+        let later = start + Duration::from_secs(2);
+        let admitted = (0..10).filter(|_| limiter.admit(&custom_log("network"), later)).count();
+        assert_eq!(admitted, 2);
+        assert_eq!(limiter.metrics(), RateLimitMetrics { admitted: 5, suppressed: 15 });
+    }
+
+    #[test]
+    fn test_summaries() {
+        let mut limiter = RateLimiter::default();
+        limiter.add_limit(RateLimit::custom_type("network", 0, 0.0));
+        let start = Instant::now();
+
+        for _ in 0..4210 {
+            limiter.admit(&custom_log("network"), start);
+        }
+        assert!(limiter.take_summaries(start + Duration::from_secs(1)).is_empty());
+
+        let summaries = limiter.take_summaries(start + Duration::from_secs(5));
+        assert_eq!(summaries, vec!["suppressed 4,210 network messages in 5s".to_string()]);
+        assert!(!limiter.has_pending());
+    }
+
+    #[test]
+    fn test_format_count() {
+        assert_eq!(format_count(0), "0");
+        assert_eq!(format_count(999), "999");
+        assert_eq!(format_count(1000), "1,000");
+        assert_eq!(format_count(1234567), "1,234,567");
+    }
+}
@@ -0,0 +1,176 @@
+//! Per-level retention of log entries.
+//!
+//! `max_logs` limits the buffer as a whole, so a flood of low severity
+//! entries can evict the rare entries that matter. Retention rules limit
+//! the entries of specific levels by count ("keep the last 10,000 DEBUG
+//! entries") and/or by age ("drop DEBUG entries older than 10 minutes").
+//! Rules are applied by the logger state whenever an entry is stored,
+//! before `max_logs`, which then only evicts entries if the rules did not
+//! bring the buffer below it.
+use std::time::Duration;
+
+use chrono::{DateTime, Local};
+
+use crate::logger::LogType;
+use crate::payload::LoggerPayload;
+
+/// RetentionRule
+///
+/// Limits how many entries of the given levels are kept, and for how long.
+/// A rule with several levels limits them as one group, e.g. "all warnings
+/// and errors up to 2,000".
+#[derive(Clone, Debug, PartialEq)]
+pub struct RetentionRule {
+    /// Levels (or custom types) the rule applies to
+    pub levels: Vec<LogType>,
+    /// Keep at most this many of the newest matching entries
+    pub max_entries: Option<usize>,
+    /// Drop matching entries last seen longer ago than this
+    pub max_age: Option<Duration>,
+}
+
+impl RetentionRule {
+    /// Create a rule for a group of levels without any limits
+    pub fn new(levels: &[LogType]) -> Self {
+        Self {
+            levels: levels.to_vec(),
+            max_entries: None,
+            max_age: None,
+        }
+    }
+
+    /// Create a rule for a single level without any limits
+    pub fn level(level: LogType) -> Self {
+        Self::new(&[level])
+    }
+
+    /// Keep at most the last `count` matching entries
+    pub fn keep_last(mut self, count: usize) -> Self {
+        self.max_entries = Some(count);
+        self
+    }
+
+    /// Drop matching entries older than `age`
+    pub fn max_age(mut self, age: Duration) -> Self {
+        self.max_age = Some(age);
+        self
+    }
+
+    /// Check if a log entry falls under this rule
+    pub fn matches(&self, log: &LoggerPayload) -> bool {
+        self.levels.iter().any(|level| level.matches(log))
+    }
+
+    /// Count the entries falling under this rule
+    pub fn count(&self, logs: &[LoggerPayload]) -> usize {
+        logs.iter().filter(|log| self.matches(log)).count()
+    }
+
+    /// Human readable description ("WARNING+ERROR 12/2000, max 10 min")
+    pub fn describe(&self, logs: &[LoggerPayload]) -> String {
+        let levels: Vec<String> = self.levels.iter().map(|level| format!("{:?}", level)).collect();
+        let mut text = format!("{} {}", levels.join("+"), self.count(logs));
+        if let Some(max_entries) = self.max_entries {
+            text.push_str(&format!("/{}", max_entries));
+        }
+        if let Some(max_age) = self.max_age {
+            text.push_str(&format!(", max {}", format_age(max_age)));
+        }
+        text
+    }
+}
+
+/// Remove the entries the rules do not retain, returning them.
+///
+/// Entries are removed oldest first. Age is measured from the last time an
+/// entry was seen, so deduplicated rows stay while they keep repeating.
+pub fn apply_retention(rules: &[RetentionRule], logs: &mut Vec<LoggerPayload>, now: DateTime<Local>) -> Vec<LoggerPayload> {
+    let mut removed = Vec::new();
+    for rule in rules {
+        if let Some(max_age) = rule.max_age
+            && let Some(cutoff) = chrono::Duration::from_std(max_age)
+                .ok()
+                .and_then(|age| now.checked_sub_signed(age))
+        {
+            removed.extend(logs.extract_if(.., |log| rule.matches(log) && log.last_seen_at < cutoff));
+        }
+
+        if let Some(max_entries) = rule.max_entries {
+            let mut excess = rule.count(logs).saturating_sub(max_entries);
+            if excess > 0 {
+                removed.extend(logs.extract_if(.., |log| {
+                    if excess > 0 && rule.matches(log) {
+                        excess -= 1;
+                        true
+                    } else {
+                        false
+                    }
+                }));
+            }
+        }
+    }
+    removed
+}
+
+/// Format a duration as "10 min", "90 s" or "2 h"
+fn format_age(age: Duration) -> String {
+    let seconds = age.as_secs();
+    if seconds >= 3600 && seconds.is_multiple_of(3600) {
+        format!("{} h", seconds / 3600)
+    } else if seconds >= 60 && seconds.is_multiple_of(60) {
+        format!("{} min", seconds / 60)
+    } else {
+        format!("{} s", seconds)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn log(level: &str, message: &str) -> LoggerPayload {
+        let mut payload = LoggerPayload::new();
+        match level {
+            "debug" => payload.debug(),
+            "error" => payload.error(),
+            _ => payload.info(),
+        };
+        payload.message(message.to_string()).update();
+        payload
+    }
+
+    #[test]
+    fn test_count_retention() {
+        let rules = vec![RetentionRule::level(LogType::Debug).keep_last(2)];
+        let mut logs = vec![
+            log("error", "disk failed"),
+            log("debug", "poll 1"),
+            log("debug", "poll 2"),
+            log("info", "started"),
+            log("debug", "poll 3"),
+        ];
+        apply_retention(&rules, &mut logs, Local::now());
+
+        let messages: Vec<&str> = logs.iter().map(|log| log.log_message.content.value.as_str()).collect();
+        assert_eq!(messages, vec!["disk failed", "poll 2", "started", "poll 3"]);
+        assert_eq!(rules[0].describe(&logs), "DEBUG 2/2");
+    }
+
+    #[test]
+    fn test_age_retention() {
+        let rules = vec![
+            RetentionRule::new(&[LogType::Debug, LogType::Info]).max_age(Duration::from_secs(600)),
+        ];
+        let mut old = log("debug", "old poll");
+        old.last_seen_at = Local::now() - chrono::Duration::minutes(11);
+        let mut old_error = log("error", "old error");
+        old_error.last_seen_at = old.last_seen_at;
+        let mut logs = vec![old, old_error, log("debug", "new poll")];
+
+        apply_retention(&rules, &mut logs, Local::now());
+
+        let messages: Vec<&str> = logs.iter().map(|log| log.log_message.content.value.as_str()).collect();
+        assert_eq!(messages, vec!["old error", "new poll"]);
+        assert_eq!(rules[0].describe(&logs), "DEBUG+INFO 1, max 10 min");
+    }
+}
@@ -0,0 +1,199 @@
+//! Search within the displayed log entries.
+//!
+//! Unlike the text filter of `LogFilter`, a search does not hide any rows.
+//! All matches are highlighted inline and the user can step through the
+//! matching rows ("3 of 57") with F3 / Shift+F3.
+use std::ops::Range;
+
+use eframe::egui;
+use egui::text::LayoutJob;
+use regex::{Regex, RegexBuilder};
+
+/// Background of highlighted matches
+pub const MATCH_BACKGROUND: egui::Color32 = egui::Color32::from_rgba_premultiplied(60, 47, 0, 60);
+/// Background of the matches in the current hit row
+pub const CURRENT_MATCH_BACKGROUND: egui::Color32 = egui::Color32::from_rgba_premultiplied(150, 82, 0, 150);
+
+/// LogSearch
+///
+/// State of the search bar (opened with Ctrl+F).
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct LogSearch {
+    /// Whether the search bar is shown
+    pub open: bool,
+    /// Text or regular expression to search for
+    pub query: String,
+    /// Interpret the query as a regular expression
+    pub regex: bool,
+    /// Match upper and lower case exactly
+    pub case_sensitive: bool,
+    /// Sequence number of the current hit
+    pub current: Option<u64>,
+}
+
+impl LogSearch {
+    /// Build the matcher for the query.
+    ///
+    /// Returns `Ok(None)` if there is nothing to search for and an error
+    /// message if the query is not a valid regular expression.
+    pub fn matcher(&self) -> Result<Option<SearchMatcher>, String> {
+        if !self.open || self.query.is_empty() {
+            return Ok(None);
+        }
+
+        let pattern = if self.regex {
+            self.query.clone()
+        } else {
+            regex::escape(&self.query)
+        };
+
+        RegexBuilder::new(&pattern)
+            .case_insensitive(!self.case_sensitive)
+            .build()
+            .map(|regex| Some(SearchMatcher { regex }))
+            .map_err(|err| err.to_string())
+    }
+}
+
+/// SearchMatcher
+///
+/// A compiled search query.
+#[derive(Clone, Debug)]
+pub struct SearchMatcher {
+    regex: Regex,
+}
+
+impl SearchMatcher {
+    /// Check if the text contains a match
+    pub fn is_match(&self, text: &str) -> bool {
+        self.regex.is_match(text)
+    }
+
+    /// Byte ranges of all non-empty matches in the text
+    pub fn find_ranges(&self, text: &str) -> Vec<Range<usize>> {
+        self.regex
+            .find_iter(text)
+            .filter(|found| !found.is_empty())
+            .map(|found| found.range())
+            .collect()
+    }
+
+    /// Give the matches in the text of a layout job a background color,
+    /// splitting sections at the match boundaries
+    pub fn highlight(&self, job: LayoutJob, background: egui::Color32) -> LayoutJob {
+        let ranges = self.find_ranges(&job.text);
+        if ranges.is_empty() {
+            return job;
+        }
+
+        let mut highlighted = LayoutJob {
+            sections: Vec::new(),
+            ..job.clone()
+        };
+
+        for section in job.sections {
+            // Boundaries within this section where highlighting starts or stops
+            let mut cuts = vec![section.byte_range.start, section.byte_range.end];
+            for range in &ranges {
+                for cut in [range.start, range.end] {
+                    if section.byte_range.contains(&cut) {
+                        cuts.push(cut);
+                    }
+                }
+            }
+            cuts.sort_unstable();
+            cuts.dedup();
+
+            for (index, part) in cuts.windows(2).enumerate() {
+                let mut format = section.format.clone();
+                if ranges.iter().any(|range| range.start <= part[0] && part[1] <= range.end) {
+                    format.background = background;
+                }
+                highlighted.sections.push(egui::text::LayoutSection {
+                    leading_space: if index == 0 { section.leading_space } else { 0.0 },
+                    byte_range: part[0]..part[1],
+                    format,
+                });
+            }
+        }
+
+        highlighted
+    }
+}
+
+/// Find the hit after (or before) the current one, wrapping around.
+///
+/// `hits` are the sequence numbers of the matching rows in display order.
+/// Without a current hit (or if it no longer matches) the first hit in the
+/// search direction is returned.
+pub fn step_hit(hits: &[u64], current: Option<u64>, forward: bool) -> Option<u64> {
+    if hits.is_empty() {
+        return None;
+    }
+
+    let position = current.and_then(|current| hits.iter().position(|hit| *hit == current));
+    let index = match (position, forward) {
+        (Some(position), true) => (position + 1) % hits.len(),
+        (Some(position), false) => (position + hits.len() - 1) % hits.len(),
+        (None, true) => 0,
+        (None, false) => hits.len() - 1,
+    };
+    Some(hits[index])
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn search(query: &str, regex: bool, case_sensitive: bool) -> SearchMatcher {
+        LogSearch {
+            open: true,
+            query: query.to_string(),
+            regex,
+            case_sensitive,
+            current: None,
+        }
+        .matcher()
+        .unwrap()
+        .unwrap()
+    }
+
+    #[test]
+    fn test_matcher_options() {
+        assert_eq!(search("err", false, false).find_ranges("Error: err"), vec![0..3, 7..10]);
+        assert_eq!(search("err", false, true).find_ranges("Error: err"), vec![7..10]);
+        assert!(search("a.c", false, false).find_ranges("abc").is_empty());
+        assert_eq!(search(r"\d+ms", true, false).find_ranges("took 42ms"), vec![5..9]);
+
+        let invalid = LogSearch { open: true, query: "(".to_string(), regex: true, ..Default::default() };
+        assert!(invalid.matcher().is_err());
+        assert!(LogSearch::default().matcher().unwrap().is_none());
+    }
+
+    #[test]
+    fn test_highlight_splits_sections() {
+        let font_id = egui::FontId::monospace(12.0);
+        let mut job = LayoutJob::default();
+        job.append("disk ", 0.0, egui::TextFormat::simple(font_id.clone(), egui::Color32::WHITE));
+        job.append("failed: disk", 0.0, egui::TextFormat::simple(font_id, egui::Color32::RED));
+
+        let job = search("k f", false, false).highlight(job, MATCH_BACKGROUND);
+        let parts: Vec<(&str, bool)> = job.sections
+            .iter()
+            .map(|section| (&job.text[section.byte_range.clone()], section.format.background == MATCH_BACKGROUND))
+            .collect();
+        assert_eq!(parts, vec![("dis", false), ("k ", true), ("f", true), ("ailed: disk", false)]);
+    }
+
+    #[test]
+    fn test_step_hit() {
+        let hits = [9, 7, 4];
+        assert_eq!(step_hit(&hits, None, true), Some(9));
+        assert_eq!(step_hit(&hits, None, false), Some(4));
+        assert_eq!(step_hit(&hits, Some(7), true), Some(4));
+        assert_eq!(step_hit(&hits, Some(4), true), Some(9));
+        assert_eq!(step_hit(&hits, Some(9), false), Some(4));
+        assert_eq!(step_hit(&hits, Some(5), true), Some(9));
+        assert_eq!(step_hit(&[], Some(5), true), None);
+    }
+}
@@ -0,0 +1,94 @@
+//! Application-defined toolbar actions.
+//!
+//! Besides the built-in buttons chosen in `LoggerConfig::toolbar`, an
+//! application can register its own buttons and menus on the logger state.
+//! Their callbacks receive the shared state when clicked, so there is no
+//! flag in egui memory to poll. Actions are shown left of the built-in
+//! buttons, in the order they were registered.
+use std::sync::Arc;
+
+use egui_mobius_reactive::Dynamic;
+
+use crate::logger::ReactiveEventLoggerState;
+
+/// Callback of a toolbar button
+pub type ToolbarCallback = Arc<dyn Fn(&Dynamic<ReactiveEventLoggerState>) + Send + Sync>;
+
+/// Callback adding the contents of a toolbar menu
+pub type ToolbarMenuCallback = Arc<dyn Fn(&mut egui::Ui, &Dynamic<ReactiveEventLoggerState>) + Send + Sync>;
+
+/// ToolbarAction
+///
+/// A button or drop-down menu added to the logger toolbar by the application.
+#[derive(Clone)]
+pub struct ToolbarAction {
+    /// Text of the button or menu
+    pub label: String,
+    /// Tooltip of the button or menu
+    pub hover_text: Option<String>,
+    /// What happens when the action is used
+    pub kind: ToolbarActionKind,
+}
+
+/// ToolbarActionKind
+///
+/// Whether a toolbar action is a button or a menu.
+#[derive(Clone)]
+pub enum ToolbarActionKind {
+    /// Button calling its callback when clicked
+    Button(ToolbarCallback),
+    /// Menu whose contents are added by the callback while it is open
+    Menu(ToolbarMenuCallback),
+}
+
+impl ToolbarAction {
+    /// Button calling `on_click` with the logger state when clicked
+    pub fn button(
+        label: &str,
+        on_click: impl Fn(&Dynamic<ReactiveEventLoggerState>) + Send + Sync + 'static,
+    ) -> Self {
+        Self {
+            label: label.to_string(),
+            hover_text: None,
+            kind: ToolbarActionKind::Button(Arc::new(on_click)),
+        }
+    }
+
+    /// Menu whose entries are added by `add_contents` while it is open
+    pub fn menu(
+        label: &str,
+        add_contents: impl Fn(&mut egui::Ui, &Dynamic<ReactiveEventLoggerState>) + Send + Sync + 'static,
+    ) -> Self {
+        Self {
+            label: label.to_string(),
+            hover_text: None,
+            kind: ToolbarActionKind::Menu(Arc::new(add_contents)),
+        }
+    }
+
+    /// Set the tooltip
+    pub fn hover_text(mut self, text: &str) -> Self {
+        self.hover_text = Some(text.to_string());
+        self
+    }
+
+    /// Show the action in the toolbar
+    pub(crate) fn show(&self, ui: &mut egui::Ui, state: &Dynamic<ReactiveEventLoggerState>) {
+        let response = match &self.kind {
+            ToolbarActionKind::Button(on_click) => {
+                let response = ui.button(&self.label);
+                if response.clicked() {
+                    on_click(state);
+                }
+                response
+            }
+            ToolbarActionKind::Menu(add_contents) => {
+                ui.menu_button(&self.label, |ui| add_contents(ui, state)).response
+            }
+        };
+
+        if let Some(text) = &self.hover_text {
+            response.on_hover_text(text);
+        }
+    }
+}
@@ -1,5 +1,5 @@
 use eframe::egui;
-use egui_lens::{ReactiveEventLogger, ReactiveEventLoggerState, LogColors};
+use egui_lens::{ReactiveEventLogger, ReactiveEventLoggerState, LogColors, ToolbarAction};
 use egui_mobius_reactive::Dynamic;
 
 // Import platform modules
@@ -54,6 +54,18 @@ impl ExampleApp {
         banner.format();
         details.get_os();
         
+        // System Info button in the logger toolbar, logging fresh system details
+        let system_details = details.clone();
+        let banner_message = banner.message.clone();
+        logger_state.lock().add_toolbar_action(
+            ToolbarAction::button("📊 System Info", move |state| {
+                let logger = ReactiveEventLogger::new(state);
+                logger.log_info(&system_details.clone().format_os());
+                logger.log_info(&banner_message);
+            })
+            .hover_text("Log the system details"),
+        );
+        
         // Create the app
         let app = Self {
             logger_state,
@@ -95,33 +107,18 @@ impl ExampleApp {
         logger.log_custom("http", "GET /api/users - 200 OK - 12ms");
         logger.log_custom("websocket", "Client connected: user_123");
         logger.log_custom("auth", "JWT token issued");
+        
+        // Output forwarded from CLI tools keeps its ANSI colors
+        logger.log_info("\x1b[1;32m   Compiling\x1b[0m egui_lens v0.1.0 \x1b[2m(/crates/egui_lens)\x1b[0m");
+        logger.log_warning("\x1b[33mwarning\x1b[0m: unused variable: \x1b[4m`frame`\x1b[24m");
+        
+        // JSON payloads are shown as compact previews and as a tree in the detail pane
+        logger.log_custom("http", r#"{"method": "GET", "path": "/api/users", "status": 200, "cached": false, "users": [{"id": 1, "name": "ada"}, {"id": 2, "name": "linus"}]}"#);
     }
 }
 
 impl eframe::App for ExampleApp {
     fn update(&mut self, ctx: &egui::Context, _frame: &mut eframe::Frame) {
-        // Check for "show system info" request from the logger
-        let show_system_info = ctx.memory(|mem| {
-            mem.data.get_temp::<bool>(egui::Id::new("show_system_info")).unwrap_or(false)
-        });
-        
-        if show_system_info {
-            // Clear the flag
-            ctx.memory_mut(|mem| {
-                mem.data.remove::<bool>(egui::Id::new("show_system_info"));
-            });
-            
-            // Create a logger to display system info
-            let logger = ReactiveEventLogger::with_colors(&self.logger_state, &self.log_colors);
-            
-            // Log system details
-            let details_text = self.details.format_os();
-            logger.log_info(&details_text);
-            
-            // Then log banner message
-            logger.log_info(&self.banner.message);
-        }
-        
         egui::CentralPanel::default().show(ctx, |ui| {
             ui.heading("Custom Log Types Example");
             ui.add_space(8.0);
@@ -17,7 +17,7 @@ mod ui;
 use platform::parameters::gui;
 use ui::{settings_panel, control_panel, TaffyPanel, sd_panel};
 // Import the ReactiveEventLogger from egui_lens
-use egui_lens::{ReactiveEventLogger, ReactiveEventLoggerState, LogColors};
+use egui_lens::{ReactiveEventLogger, ReactiveEventLoggerState, LogColors, LogType, Deduplication, FilterPresets, ToolbarAction, AlertRule, LoggerConfig};
 
 use egui_mobius_reactive::Dynamic;
 
@@ -26,7 +26,7 @@ use egui_mobius_reactive::Dynamic;
 
 // egui and egui_dock crates
 use eframe::egui;
-use egui_dock::{DockArea, DockState, NodeIndex, Style, SurfaceIndex};
+use egui_dock::{DockArea, DockState, NodeIndex, Style, SurfaceIndex, TabIndex};
 
 // Standard library
 
@@ -146,7 +146,13 @@ impl egui_dock::TabViewer for TabViewer<'_> {
     type Tab = Tab;
 
     fn title(&mut self, tab: &mut Self::Tab) -> egui::WidgetText {
-        tab.title().into()
+        match tab.kind {
+            // Badges of the warnings and errors logged while the tab was hidden
+            TabKind::ReactiveLogger => {
+                ReactiveEventLogger::with_colors(self.reactive_logger_state, self.colors).tab_title(&tab.title())
+            }
+            _ => tab.title().into(),
+        }
     }
 
     fn ui(&mut self, ui: &mut egui::Ui, tab: &mut Self::Tab) {
@@ -172,8 +178,6 @@ pub struct MyApp {
     is_running       : bool,
     is_formatted     : bool,
     colors           : Dynamic<LogColors>,
-    banner           : platform::banner::Banner,
-    details          : platform::details::Details,
     volume_label     : String,
 }
 
@@ -188,28 +192,32 @@ impl Drop for MyApp {
         colors.save();
     }
 }
+impl MyApp {
+    /// Bring the logger tab to the front of its dock node
+    fn focus_logger_tab(&mut self) {
+        let tree = self.dock_state.main_surface_mut();
+        let found = tree.iter().enumerate().find_map(|(node, leaf)| {
+            let tab = leaf.tabs()?.iter().position(|tab| matches!(tab.kind, TabKind::ReactiveLogger))?;
+            Some((NodeIndex(node), TabIndex(tab)))
+        });
+        if let Some((node, tab)) = found {
+            tree.set_active_tab(node, tab);
+            tree.set_focused_node(node);
+        }
+    }
+}
+
 impl eframe::App for MyApp {
     fn update(&mut self, ctx: &egui::Context, _frame: &mut eframe::Frame) {
-        // Handle system info button clicked
-        let show_system_info = ctx.memory(|mem| {
-            mem.data.get_temp::<bool>(egui::Id::new("show_system_info")).unwrap_or(false)
-        });
+        // Show alerts as toasts even while the logger tab is hidden, and
+        // bring the logger to the front when the user asks to see the entry
+        let logger = ReactiveEventLogger::with_colors(&self.reactive_logger_state, &self.colors);
+        let jump_to = logger.show_alert_toasts(ctx);
         
-        if show_system_info {
-            // Clear the flag
-            ctx.memory_mut(|mem| {
-                mem.data.remove::<bool>(egui::Id::new("show_system_info"));
-            });
-            
-            // Create a temporary logger for system info output
-            let logger = ReactiveEventLogger::with_colors(&self.reactive_logger_state, &self.colors);
-            
-            // Display system details first
-            let details_text = self.details.format_os();
-            logger.log_info(&details_text);
-            
-            // Then display banner (so it appears above the details in the log)
-            logger.log_info(&self.banner.message);
+        // Keep the popped out logger window open while its tab is hidden
+        logger.show_viewport(ctx);
+        if jump_to.is_some() {
+            self.focus_logger_tab();
         }
         
         // Check if we need to update the UI state from the thread
@@ -268,8 +276,30 @@ fn main() -> Result<(), eframe::Error> {
             
             // We'll use default colors since we can't access the old format easily
             
-            // Create reactive containers for state
-            let reactive_logger_state = Dynamic::new(ReactiveEventLoggerState::new());
+            // Create reactive containers for state, collapsing repeated
+            // messages (e.g. from retry loops) into a single row
+            let mut logger_state = ReactiveEventLoggerState::new();
+            logger_state.deduplication = Deduplication::Consecutive;
+            // Restore the filter presets saved next to the logger colors
+            logger_state.filter_presets = FilterPresets::load();
+            // Command line below the table, with app commands next to help, clear and filter
+            logger_state.apply_config(LoggerConfig::new().command_line(true));
+            logger_state.commands
+                .register("preset", |args, state| {
+                    let name = args.join(" ");
+                    if state.apply_filter_preset(&name) {
+                        Ok(format!("Applied filter preset \"{}\"", name))
+                    } else {
+                        Err(format!("No filter preset named \"{}\"", name))
+                    }
+                })
+                .help("preset <name>: apply a saved filter preset");
+            // Raise alerts for errors and for bursts of warnings
+            logger_state.add_alert_rule(AlertRule::level("Error", LogType::Error));
+            logger_state.add_alert_rule(
+                AlertRule::level("Warning burst", LogType::Warning).more_than(5, std::time::Duration::from_secs(10)),
+            );
+            let reactive_logger_state = Dynamic::new(logger_state);
             let colors_dynamic = Dynamic::new(logger_colors);
             
             // Create dock state with Control and Taffy in the left panel
@@ -323,6 +353,26 @@ fn main() -> Result<(), eframe::Error> {
                 logger.log_info(&banner.message);
             }
             
+            // sysinfo command, returning fresh system details
+            let system_details = details.clone();
+            reactive_logger_state.lock().commands
+                .register("sysinfo", move |_, _| Ok(system_details.clone().format_os()))
+                .help("Show the system details");
+            
+            // System Info button in the logger toolbar, logging fresh system details
+            reactive_logger_state.lock().add_toolbar_action(
+                ToolbarAction::button("📊 System Info", move |state| {
+                    let logger = ReactiveEventLogger::new(state);
+                    
+                    // Display system details first
+                    logger.log_info(&details.clone().format_os());
+                    
+                    // Then display banner (so it appears above the details in the log)
+                    logger.log_info(&banner.message);
+                })
+                .hover_text("Log the system details"),
+            );
+            
             // Create app with loaded colors and initialized dock state
             Ok(Box::new(MyApp {
                 dock_state,
@@ -332,8 +382,6 @@ fn main() -> Result<(), eframe::Error> {
                 is_running      : false,
                 is_formatted    : false,
                 colors           : colors_dynamic,
-                banner,
-                details,
                 volume_label: String::from("DISKFORGE"),
             }))
         })
@@ -141,28 +141,38 @@ impl<'a> ControlPanel<'a> {
                         // Create a new logger for the thread
                         let logger = ReactiveEventLogger::with_colors(&reactive_logger_state_clone, &colors_clone);
                         
-                        // First step
-                        logger.log_info("[INFO] Wiping first 1MB (secure erase)");
-                        thread::sleep(Duration::from_millis(500));
-                        
-                        // Second step
-                        logger.log_info("[INFO] Partition table written (MBR)");
-                        thread::sleep(Duration::from_millis(500));
-                        
-                        // Third step
-                        logger.log_info(&format!("[INFO] {} filesystem created", format_type));
-                        thread::sleep(Duration::from_millis(500));
-                        
-                        // Fourth step
-                        logger.log_info("[INFO] Directory tree /project initialized");
-                        thread::sleep(Duration::from_millis(500));
-                        
-                        // Fifth step
-                        logger.log_info("[INFO] Pedigree metadata written");
-                        thread::sleep(Duration::from_millis(500));
-                        
-                        // Final step
-                        logger.log_info("[SUCCESS] SD card provisioning complete");
+                        // Nest all steps under a collapsible group, closed at the end of the block
+                        {
+                            let _format_group = logger.group(&format!("Format SD card ({})", format_type));
+                            
+                            // First step
+                            logger.log_info("[INFO] Wiping first 1MB (secure erase)");
+                            thread::sleep(Duration::from_millis(500));
+                            
+                            // Second step
+                            logger.log_info("[INFO] Partition table written (MBR)");
+                            thread::sleep(Duration::from_millis(500));
+                            
+                            // Filesystem steps in a nested group
+                            {
+                                let _filesystem_group = logger.group("Create filesystem");
+                                
+                                // Third step
+                                logger.log_info(&format!("[INFO] {} filesystem created", format_type));
+                                thread::sleep(Duration::from_millis(500));
+                                
+                                // Fourth step
+                                logger.log_info("[INFO] Directory tree /project initialized");
+                                thread::sleep(Duration::from_millis(500));
+                                
+                                // Fifth step
+                                logger.log_info("[INFO] Pedigree metadata written");
+                                thread::sleep(Duration::from_millis(500));
+                            }
+                            
+                            // Final step
+                            logger.log_info("[SUCCESS] SD card provisioning complete");
+                        }
                         
                         // Update the format flag using thread-safe AtomicBool
                         format_flag.store(true, std::sync::atomic::Ordering::SeqCst);
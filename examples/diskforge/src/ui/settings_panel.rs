@@ -275,7 +275,20 @@ impl<'a> SettingsPanel<'a> {
                 // Log the wipe operation
                 let msg = format!("Wipe (dd) operation started with slider={:.1}", self.slider_value);
                 reactive_logger.log_warning(&msg);
-                reactive_logger.log_warning("Starting wiping the disk with dd ...");
+                
+                // Show the wipe as a single live progress entry, updated from a
+                // background thread that simulates dd writing 1 MB blocks
+                const WIPE_BLOCKS: u64 = 64;
+                let progress = reactive_logger.progress("Wiping the disk with dd (MB)", WIPE_BLOCKS);
+                let ctx = ui.ctx().clone();
+                std::thread::spawn(move || {
+                    for block in 1..=WIPE_BLOCKS {
+                        std::thread::sleep(std::time::Duration::from_millis(50));
+                        progress.set(block);
+                    }
+                    progress.finish_ok();
+                    ctx.request_repaint();
+                });
             }
             ui.add_space(8.0);
 